@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use bevy_optix::camera::{Binded, MainCamera};
+use bevy_optix::headless::OptixTestAppExt;
+use bevy_optix::shake::Shake;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_optix_test_plugins();
+
+    let target = app.world_mut().spawn(Transform::default()).id();
+    app.world_mut()
+        .spawn((MainCamera, Camera2d, Binded(target), Shake::default()));
+    app.update();
+    app
+}
+
+fn follow(c: &mut Criterion) {
+    let mut app = app();
+    c.bench_function("camera_follow", |b| {
+        b.iter(|| {
+            let world = app.world_mut();
+            for mut transform in world
+                .query_filtered::<&mut Transform, Without<MainCamera>>()
+                .iter_mut(world)
+            {
+                transform.translation.x += 1.;
+            }
+            app.update();
+        })
+    });
+}
+
+fn shake(c: &mut Criterion) {
+    let mut app = app();
+    c.bench_function("camera_shake", |b| {
+        b.iter(|| {
+            let world = app.world_mut();
+            for mut shake in world.query::<&mut Shake>().iter_mut(world) {
+                shake.add_trauma(1.);
+            }
+            app.update();
+        })
+    });
+}
+
+criterion_group!(benches, follow, shake);
+criterion_main!(benches);
@@ -0,0 +1,110 @@
+//! Measures a frame with five stacked post processes.
+//!
+//! Renders offscreen, so a GPU adapter (or a software rasterizer) is required. The benchmarks are
+//! skipped without one.
+
+use bevy::ecs::component::Mutable;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::window::ExitCondition;
+use bevy_optix::calibration::{DisplayCalibrationPlugin, DisplayCalibrationSettings};
+use bevy_optix::colorblind::{ColorblindFilterPlugin, ColorblindFilterSettings};
+use bevy_optix::glitch::{GlitchPlugin, GlitchSettings};
+use bevy_optix::posterize::{PosterizePlugin, PosterizeSettings};
+use bevy_optix::vhs::{VhsPlugin, VhsSettings};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn gpu_adapter_available() -> bool {
+    let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::from_env_or_default());
+    bevy::tasks::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        .is_some()
+}
+
+/// Builds the benchmarked app, or `None` without a GPU adapter, in which case the renderer panics.
+fn app() -> Option<App> {
+    if !gpu_adapter_available() {
+        eprintln!("no GPU adapter is available, skipping post process benchmarks");
+        return None;
+    }
+
+    let mut app = App::new();
+    app.add_plugins((
+        DefaultPlugins.set(WindowPlugin {
+            primary_window: None,
+            exit_condition: ExitCondition::DontExit,
+            close_when_requested: false,
+        }),
+        GlitchPlugin::default(),
+        PosterizePlugin,
+        VhsPlugin,
+        ColorblindFilterPlugin,
+        DisplayCalibrationPlugin,
+    ));
+    app.finish();
+    app.cleanup();
+
+    let mut target = Image::new_fill(
+        Extent3d {
+            width: 640,
+            height: 360,
+            ..default()
+        },
+        TextureDimension::D2,
+        &[0; 4],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    target.texture_descriptor.usage |= TextureUsages::RENDER_ATTACHMENT;
+    let target = app.world_mut().resource_mut::<Assets<Image>>().add(target);
+
+    app.world_mut().spawn((
+        Camera2d,
+        Camera {
+            target: RenderTarget::Image(target.into()),
+            ..default()
+        },
+        GlitchSettings::default(),
+        PosterizeSettings::default(),
+        VhsSettings::default(),
+        ColorblindFilterSettings::default(),
+        DisplayCalibrationSettings::default(),
+    ));
+    app.update();
+    Some(app)
+}
+
+fn unchanged(c: &mut Criterion) {
+    let Some(mut app) = app() else {
+        return;
+    };
+    c.bench_function("post_process_unchanged", |b| b.iter(|| app.update()));
+}
+
+/// Marks `T` as changed, so that it is extracted and uploaded again.
+fn touch<T: Component<Mutability = Mutable>>(world: &mut World) {
+    for mut settings in world.query::<&mut T>().iter_mut(world) {
+        settings.set_changed();
+    }
+}
+
+fn changed(c: &mut Criterion) {
+    let Some(mut app) = app() else {
+        return;
+    };
+    c.bench_function("post_process_changed", |b| {
+        b.iter(|| {
+            let world = app.world_mut();
+            touch::<GlitchSettings>(world);
+            touch::<PosterizeSettings>(world);
+            touch::<VhsSettings>(world);
+            touch::<ColorblindFilterSettings>(world);
+            touch::<DisplayCalibrationSettings>(world);
+            app.update();
+        })
+    });
+}
+
+criterion_group!(benches, unchanged, changed);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy::transform::TransformPlugin;
+use bevy_optix::zorder::{YOrigin, ZOrderPlugin};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+const ENTITIES: usize = 10_000;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TransformPlugin, ZOrderPlugin));
+    for i in 0..ENTITIES {
+        app.world_mut()
+            .spawn((Transform::from_xyz(0., i as f32, 0.), YOrigin(0.)));
+    }
+    app.update();
+    app
+}
+
+fn moving(c: &mut Criterion) {
+    let mut app = app();
+    c.bench_function("zorder_moving", |b| {
+        b.iter(|| {
+            let world = app.world_mut();
+            for mut transform in world.query::<&mut Transform>().iter_mut(world) {
+                transform.translation.y += 1.;
+            }
+            app.update();
+        })
+    });
+}
+
+fn stationary(c: &mut Criterion) {
+    let mut app = app();
+    c.bench_function("zorder_stationary", |b| b.iter(|| app.update()));
+}
+
+criterion_group!(benches, moving, stationary);
+criterion_main!(benches);
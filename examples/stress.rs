@@ -0,0 +1,197 @@
+//! Stresses the zorder, camera, and post process systems together: 50k y-sorted sprites, six
+//! stacked post processes, and a continuously shaking camera.
+//!
+//! ```sh
+//! cargo run --release --example stress
+//! ```
+//!
+//! With `--headless`, the scene renders offscreen for a fixed number of frames and exits with an
+//! error if the mean frame time exceeds `OPTIX_FRAME_BUDGET_MS` (default 33). The check is skipped
+//! when no GPU adapter, or software rasterizer, is available:
+//!
+//! ```sh
+//! OPTIX_FRAME_BUDGET_MS=20 cargo run --release --example stress -- --headless
+//! ```
+
+use bevy::diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::window::ExitCondition;
+use bevy_optix::calibration::{DisplayCalibrationPlugin, DisplayCalibrationSettings};
+use bevy_optix::camera::{CameraAnimationPlugin, MainCamera};
+use bevy_optix::color_temperature::{ColorTemperaturePlugin, ColorTemperatureSettings};
+use bevy_optix::colorblind::{ColorblindFilterPlugin, ColorblindFilterSettings};
+use bevy_optix::glitch::{GlitchPlugin, GlitchSettings};
+use bevy_optix::posterize::{PosterizePlugin, PosterizeSettings};
+use bevy_optix::shake::{ScreenShakePlugin, Shake};
+use bevy_optix::vhs::{VhsPlugin, VhsSettings};
+use bevy_optix::zorder::{YOrigin, ZOrderPlugin};
+use std::time::{Duration, Instant};
+
+const SPRITES: usize = 50_000;
+const WARMUP_FRAMES: usize = 60;
+const MEASURED_FRAMES: usize = 600;
+const DEFAULT_BUDGET_MS: f64 = 33.;
+
+fn main() -> AppExit {
+    let headless = std::env::args().any(|arg| arg == "--headless");
+
+    let mut app = App::new();
+    if headless && !gpu_adapter_available() {
+        // the renderer panics without an adapter, so only the logger is added
+        app.add_plugins(LogPlugin::default());
+        warn!("no GPU adapter is available, skipping the frame budget check");
+        return AppExit::Success;
+    }
+
+    if headless {
+        app.add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: None,
+            exit_condition: ExitCondition::DontExit,
+            close_when_requested: false,
+        }));
+    } else {
+        app.add_plugins((
+            DefaultPlugins,
+            FrameTimeDiagnosticsPlugin::default(),
+            LogDiagnosticsPlugin::default(),
+        ));
+    }
+
+    app.add_plugins((
+        CameraAnimationPlugin::default(),
+        ScreenShakePlugin::default(),
+        ZOrderPlugin,
+        GlitchPlugin::default(),
+        PosterizePlugin,
+        VhsPlugin,
+        ColorblindFilterPlugin,
+        DisplayCalibrationPlugin,
+        ColorTemperaturePlugin,
+    ))
+    .add_systems(Update, (shake, wander));
+
+    if headless {
+        return run_headless(app);
+    }
+
+    app.add_systems(Startup, |mut commands: Commands| {
+        commands.spawn(camera(RenderTarget::default()));
+        spawn_sprites(&mut commands);
+    });
+    app.run()
+}
+
+/// Renders offscreen and measures the mean frame time against the budget.
+fn run_headless(mut app: App) -> AppExit {
+    app.finish();
+    app.cleanup();
+
+    let mut target = Image::new_fill(
+        Extent3d {
+            width: 1280,
+            height: 720,
+            ..default()
+        },
+        TextureDimension::D2,
+        &[0; 4],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    target.texture_descriptor.usage |= TextureUsages::RENDER_ATTACHMENT;
+    let target = app.world_mut().resource_mut::<Assets<Image>>().add(target);
+
+    {
+        let world = app.world_mut();
+        let mut commands = world.commands();
+        commands.spawn(camera(RenderTarget::Image(target.into())));
+        spawn_sprites(&mut commands);
+        world.flush();
+    }
+
+    // pipelines compile during the first frames
+    for _ in 0..WARMUP_FRAMES {
+        app.update();
+    }
+
+    let mut frames = Vec::with_capacity(MEASURED_FRAMES);
+    for _ in 0..MEASURED_FRAMES {
+        let start = Instant::now();
+        app.update();
+        frames.push(start.elapsed());
+    }
+
+    frames.sort();
+    let mean = frames.iter().sum::<Duration>() / MEASURED_FRAMES as u32;
+    let p99 = frames[MEASURED_FRAMES * 99 / 100];
+    let budget = std::env::var("OPTIX_FRAME_BUDGET_MS")
+        .ok()
+        .and_then(|budget| budget.parse().ok())
+        .unwrap_or(DEFAULT_BUDGET_MS);
+
+    info!(
+        "{MEASURED_FRAMES} frames: mean {:.2}ms, p99 {:.2}ms, budget {budget:.2}ms",
+        mean.as_secs_f64() * 1000.,
+        p99.as_secs_f64() * 1000.,
+    );
+
+    if mean.as_secs_f64() * 1000. > budget {
+        error!("mean frame time exceeds the budget");
+        AppExit::error()
+    } else {
+        AppExit::Success
+    }
+}
+
+fn gpu_adapter_available() -> bool {
+    let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::from_env_or_default());
+    bevy::tasks::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        .is_some()
+}
+
+fn camera(target: RenderTarget) -> impl Bundle {
+    (
+        MainCamera,
+        Camera2d,
+        Camera {
+            target,
+            ..default()
+        },
+        Shake::default(),
+        GlitchSettings::default(),
+        PosterizeSettings::default(),
+        VhsSettings::default(),
+        ColorblindFilterSettings::default(),
+        DisplayCalibrationSettings::default(),
+        ColorTemperatureSettings::new(0.2, 0., 0.),
+    )
+}
+
+fn spawn_sprites(commands: &mut Commands) {
+    let columns = (SPRITES as f32).sqrt() as usize;
+    commands.spawn_batch((0..SPRITES).map(move |i| {
+        let position = Vec2::new((i % columns) as f32, (i / columns) as f32) * 4. - 450.;
+        (
+            Sprite::from_color(Color::hsl(i as f32 % 360., 0.6, 0.5), Vec2::splat(6.)),
+            Transform::from_translation(position.extend(0.)),
+            YOrigin(-3.),
+        )
+    }));
+}
+
+fn shake(mut shakes: Query<&mut Shake>) {
+    for mut shake in shakes.iter_mut() {
+        shake.add_trauma(0.5);
+    }
+}
+
+/// Moves a tenth of the sprites every frame, so their z order is recomputed.
+fn wander(mut sprites: Query<&mut Transform, (With<Sprite>, With<YOrigin>)>, time: Res<Time>) {
+    let offset = time.elapsed_secs().sin() * 0.5;
+    for mut transform in sprites.iter_mut().step_by(10) {
+        transform.translation.y += offset;
+    }
+}
@@ -0,0 +1,108 @@
+//! Settings that tone down optix effects for players who need it.
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Limits flashing effects, such as [`GlitchSettings`](crate::glitch::GlitchSettings) and
+/// [`DamageVignette`](crate::damage::DamageVignette) pulses, for photosensitive players.
+///
+/// Effects behave as normal when this resource does not exist.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct PhotosensitivitySettings {
+    /// The most flashes allowed per second, flashes in between are merged.
+    pub max_flash_frequency: f32,
+    /// Caps the intensity of flashing effects, in the range `[0, 1]`.
+    pub max_intensity: f32,
+    /// Replaces strobes with gentle fades.
+    pub gentle_fades: bool,
+}
+
+impl Default for PhotosensitivitySettings {
+    /// Follows the WCAG guideline of no more than three flashes per second.
+    fn default() -> Self {
+        Self {
+            max_flash_frequency: 3.,
+            max_intensity: 1.,
+            gentle_fades: false,
+        }
+    }
+}
+
+impl PhotosensitivitySettings {
+    /// Fewer, gentler, and dimmer flashes.
+    pub fn accessible() -> Self {
+        Self {
+            max_flash_frequency: 1.,
+            max_intensity: 0.5,
+            gentle_fades: true,
+        }
+    }
+
+    pub fn cap(&self, intensity: f32) -> f32 {
+        intensity.min(self.max_intensity)
+    }
+
+    /// The shortest time between flashes, in seconds.
+    pub fn min_flash_interval(&self) -> f32 {
+        1. / self.max_flash_frequency.max(f32::EPSILON)
+    }
+
+    /// The rate at which a flash rises, replaced by `fade` with gentle fades.
+    pub fn attack(&self, attack: f32, fade: f32) -> f32 {
+        if self.gentle_fades {
+            attack.min(fade)
+        } else {
+            attack
+        }
+    }
+
+    /// The time over which a flash rises, lengthened to `release` with gentle fades.
+    ///
+    /// [`Self::attack`] for effects timed by durations rather than rates.
+    pub fn attack_duration(&self, attack: Duration, release: Duration) -> Duration {
+        if self.gentle_fades {
+            attack.max(release)
+        } else {
+            attack
+        }
+    }
+
+    /// Whether a flash `since` the previous one is within the [`Self::min_flash_interval`], and
+    /// should be merged into it.
+    pub fn merges(&self, since: Duration) -> bool {
+        since.as_secs_f32() < self.min_flash_interval()
+    }
+}
+
+/// Tones down optix driven motion, such as [`Shake`](crate::shake::Shake),
+/// [`IdleDrift`](crate::bias::IdleDrift), [`PeekInput`](crate::bias::PeekInput), and
+/// [`GlitchSettings`](crate::glitch::GlitchSettings), with a single toggle.
+///
+/// Effects behave as normal when this resource does not exist.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct ReducedMotion {
+    pub enabled: bool,
+    /// The fraction of motion kept while enabled, `0` disables motion entirely.
+    pub scale: f32,
+}
+
+impl Default for ReducedMotion {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            scale: 0.25,
+        }
+    }
+}
+
+impl ReducedMotion {
+    /// The factor applied to the strength of motion effects.
+    pub fn factor(&self) -> f32 {
+        if self.enabled { self.scale } else { 1. }
+    }
+}
+
+/// The [`ReducedMotion::factor`], or `1` without the resource.
+pub(crate) fn motion_factor(reduced: Option<&ReducedMotion>) -> f32 {
+    reduced.map_or(1., ReducedMotion::factor)
+}
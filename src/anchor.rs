@@ -1,11 +1,108 @@
-use crate::camera::{MainCamera, MoveTo};
+use crate::camera::{Binded, MainCamera, MoveTo, ResolvedCameraOffset, single_or_warn};
+use crate::ease::OptixEase;
+use crate::smoothing::smooth_damp;
+use bevy::ecs::{
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    system::SystemParam,
+};
 use bevy::prelude::*;
+use std::any::TypeId;
+use std::sync::Arc;
 use std::time::Duration;
 
+/// Schedules the anchor systems in the [`AnchorSystem`] sets.
+///
+/// Added by the [`CameraAnimationPlugin`](crate::camera::CameraAnimationPlugin) if missing. Add
+/// [`AnchorPlugin::manual`] beforehand to schedule the anchor systems yourself, e.g. in a
+/// [`FixedUpdate`] driven game loop:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_optix::{anchor::*, camera::CameraAnimationPlugin};
+/// App::new()
+///     .add_plugins((AnchorPlugin::manual(), CameraAnimationPlugin::default()))
+///     .add_systems(
+///         FixedUpdate,
+///         (
+///             (bind_to_dyn_anchor, unbind_dyn_anchor, anchor_handoff),
+///             (anchor, follow_dyn_anchor),
+///         )
+///             .chain(),
+///     );
+/// ```
+pub struct AnchorPlugin {
+    /// Adds the anchor systems to `schedule`.
+    pub scheduled: bool,
+    pub schedule: InternedScheduleLabel,
+}
+
+impl Default for AnchorPlugin {
+    fn default() -> Self {
+        Self {
+            scheduled: true,
+            schedule: PostUpdate.intern(),
+        }
+    }
+}
+
+impl AnchorPlugin {
+    /// Leaves the anchor systems unscheduled.
+    pub fn manual() -> Self {
+        Self {
+            scheduled: false,
+            ..Default::default()
+        }
+    }
+}
+
+impl Plugin for AnchorPlugin {
+    fn build(&self, app: &mut App) {
+        if !self.scheduled {
+            return;
+        }
+
+        app.add_systems(
+            self.schedule,
+            (
+                (bind_to_dyn_anchor, unbind_dyn_anchor, anchor_handoff).in_set(AnchorSystem::Bind),
+                (anchor, follow_dyn_anchor).in_set(AnchorSystem::Follow),
+            ),
+        );
+    }
+}
+
+/// The anchor systems scheduled by the [`AnchorPlugin`], which run in
+/// [`CameraSystem::UpdateCamera`](crate::camera::CameraSystem::UpdateCamera).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum AnchorSystem {
+    /// Binds and releases [`DynamicCameraAnchor`]s, before the camera's follow systems, which
+    /// take priority over an [`AnchorHandoff`].
+    Bind,
+    /// Locks the camera to the active anchor, after the camera's follow systems.
+    Follow,
+}
+
 /// Position which the [`MainCamera`] will snap to when a single instance exists.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+///
+/// Only the axes set in `axes` are locked to the anchor, e.g. [`Vec2::Y`] fixes the camera's
+/// height while the x axis continues to follow the bound target.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
 #[require(Transform)]
-pub struct CameraAnchor;
+pub struct CameraAnchor {
+    pub axes: Vec2,
+}
+
+impl Default for CameraAnchor {
+    fn default() -> Self {
+        Self { axes: Vec2::ONE }
+    }
+}
+
+impl CameraAnchor {
+    pub fn with_axes(axes: Vec2) -> Self {
+        Self { axes }
+    }
+}
 
 /// Position which the [`MainCamera`] will move to when an [`AnchorTarget`] enters the anchor's
 /// translation and `radius`.
@@ -14,17 +111,102 @@ pub struct CameraAnchor;
 pub struct DynamicCameraAnchor {
     radius: f32,
     speed: f32,
+    axes: Vec2,
+    target_filter: Option<TypeId>,
+    easing: OptixEase,
 }
 
 impl DynamicCameraAnchor {
     pub fn new(radius: f32, speed: f32) -> Self {
-        Self { radius, speed }
+        Self {
+            radius,
+            speed,
+            axes: Vec2::ONE,
+            target_filter: None,
+            easing: EaseFunction::QuadraticOut.into(),
+        }
+    }
+
+    /// The easing of the move into the anchor.
+    pub fn with_easing(mut self, easing: impl Into<OptixEase>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+
+    /// Only locks the axes set in `axes` to the anchor, the remaining axes follow the
+    /// [`AnchorTarget`].
+    pub fn with_axes(mut self, axes: Vec2) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    /// Only responds to [`AnchorTarget`]s that also contain `T`, e.g. the player but not NPCs.
+    pub fn with_target_filter<T: Component>(mut self) -> Self {
+        self.target_filter = Some(TypeId::of::<T>());
+        self
+    }
+}
+
+/// Determines whether a [`DynamicCameraAnchor`] may capture the [`MainCamera`].
+///
+/// Evaluated against each [`AnchorTarget`] before the anchor binds, and every frame while bound,
+/// releasing the camera once the condition fails. Allows scripted sequences to enable and disable
+/// camera zones without despawning them, e.g. with [`set_anchor_condition`] when entering and
+/// exiting a state.
+///
+/// [`CameraAnchor`]s have no target, and only respect [`AnchorCondition::Disabled`].
+#[derive(Clone, Default, Component)]
+pub enum AnchorCondition {
+    #[default]
+    AlwaysOn,
+    Disabled,
+    /// The target must contain the component, e.g. a `Grounded` marker.
+    TargetHas(TypeId),
+    /// Evaluated with the anchor and target entities.
+    Custom(Arc<dyn Fn(EntityRef, EntityRef) -> bool + Send + Sync>),
+}
+
+impl AnchorCondition {
+    pub fn target_has<T: Component>() -> Self {
+        Self::TargetHas(TypeId::of::<T>())
+    }
+
+    pub fn custom(
+        condition: impl Fn(EntityRef, EntityRef) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self::Custom(Arc::new(condition))
+    }
+
+    pub fn allows(&self, anchor: EntityRef, target: EntityRef) -> bool {
+        match self {
+            Self::AlwaysOn => true,
+            Self::Disabled => false,
+            Self::TargetHas(id) => target.contains_type_id(*id),
+            Self::Custom(condition) => condition(anchor, target),
+        }
+    }
+}
+
+/// Inserts `condition` into every anchor marked with `M`.
+pub fn set_anchor_condition<M: Component>(
+    condition: AnchorCondition,
+) -> impl Fn(Query<Entity, With<M>>, Commands) {
+    move |anchors, mut commands| {
+        for anchor in anchors.iter() {
+            commands.entity(anchor).insert(condition.clone());
+        }
     }
 }
 
+/// Blends `target` into `anchor` along the axes of `mask`.
+fn masked(anchor: Vec3, target: Vec3, mask: Vec2) -> Vec3 {
+    (target.xy() + (anchor.xy() - target.xy()) * mask).extend(anchor.z)
+}
+
 /// Marks an entity as a valid target for triggering a [`DynamicCameraAnchor`] binding.
 ///
-/// Only one can exist at any given time.
+/// Multiple targets may exist, in which case anchors can select between them with
+/// [`DynamicCameraAnchor::with_target_filter`].
 #[derive(Debug, Default, Clone, Copy, Component)]
 #[require(Transform)]
 pub struct AnchorTarget;
@@ -32,71 +214,247 @@ pub struct AnchorTarget;
 /// A component placed into the [`MainCamera`] which points to the entity currently dynamically
 /// anchored to.
 #[derive(Component)]
-pub struct DynamicallyAnchored(Entity);
+pub struct DynamicallyAnchored {
+    anchor: Entity,
+    target: Entity,
+}
+
+impl DynamicallyAnchored {
+    pub fn anchor(&self) -> Entity {
+        self.anchor
+    }
 
-pub(crate) fn anchor(
-    mut camera: Single<&mut Transform, With<MainCamera>>,
-    anchor: Single<&Transform, (With<CameraAnchor>, Without<MainCamera>)>,
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+}
+
+/// The [`AnchorTarget`]s visible to a [`DynamicCameraAnchor`].
+#[derive(SystemParam)]
+pub struct AnchorTargets<'w, 's> {
+    targets: Query<
+        'w,
+        's,
+        (EntityRef<'static>, &'static Transform),
+        (With<AnchorTarget>, Without<MainCamera>),
+    >,
+}
+
+impl AnchorTargets<'_, '_> {
+    fn iter<'a>(
+        &'a self,
+        anchor: &'a DynamicCameraAnchor,
+    ) -> impl Iterator<Item = (EntityRef<'a>, &'a Transform)> + 'a {
+        self.targets.iter().filter(|(target, _)| {
+            anchor
+                .target_filter
+                .is_none_or(|filter| target.contains_type_id(filter))
+        })
+    }
+
+    fn get(&self, entity: Entity) -> Option<(EntityRef, &Transform)> {
+        self.targets.get(entity).ok()
+    }
+}
+
+pub fn anchor(
+    mut camera: Query<&mut Transform, With<MainCamera>>,
+    anchors: Query<(&CameraAnchor, &Transform, Option<&AnchorCondition>), Without<MainCamera>>,
 ) {
-    camera.translation = anchor.translation;
+    let Some(mut camera) = single_or_warn(camera.single_mut(), "MainCamera") else {
+        return;
+    };
+    let mut enabled = anchors
+        .iter()
+        .filter(|(_, _, condition)| !matches!(condition, Some(AnchorCondition::Disabled)));
+    let (Some((anchor, transform, _)), None) = (enabled.next(), enabled.next()) else {
+        return;
+    };
+
+    camera.translation = masked(transform.translation, camera.translation, anchor.axes);
+}
+
+pub fn follow_dyn_anchor(
+    q: Query<(&DynamicCameraAnchor, &Transform), Without<MainCamera>>,
+    targets: AnchorTargets,
+    mut camera: Query<(&mut Transform, &DynamicallyAnchored), (With<MainCamera>, Without<MoveTo>)>,
+) {
+    let Some((mut camera, anchored)) = single_or_warn(camera.single_mut(), "MainCamera") else {
+        return;
+    };
+    let Ok((anchor, anchor_transform)) = q.get(anchored.anchor) else {
+        return;
+    };
+    let Some((_, target_transform)) = targets.get(anchored.target) else {
+        return;
+    };
+
+    if anchor.axes != Vec2::ONE {
+        camera.translation = masked(
+            anchor_transform.translation,
+            target_transform.translation,
+            anchor.axes,
+        );
+    }
 }
 
-pub(crate) fn unbind_dyn_anchor(
-    q: Query<(&DynamicCameraAnchor, &Transform)>,
-    anchor_target: Single<(Entity, &Transform), With<AnchorTarget>>,
-    camera: Single<(Entity, &Transform, &DynamicallyAnchored), With<MainCamera>>,
+pub fn unbind_dyn_anchor(
+    q: Query<
+        (
+            EntityRef,
+            &DynamicCameraAnchor,
+            &Transform,
+            Option<&AnchorCondition>,
+        ),
+        Without<MainCamera>,
+    >,
+    targets: AnchorTargets,
+    camera: Query<(Entity, &DynamicallyAnchored), With<MainCamera>>,
     mut commands: Commands,
 ) {
-    let (camera, camera_transform, anchor_ref) = camera.into_inner();
-    let (target, target_transform) = anchor_target.into_inner();
-    let Ok((anchor, anchor_transform)) = q.get(anchor_ref.0) else {
+    let Some((camera, anchored)) = single_or_warn(camera.single(), "MainCamera") else {
+        return;
+    };
+    let Ok((entity, anchor, anchor_transform, condition)) = q.get(anchored.anchor) else {
         return;
     };
 
-    if target_transform
-        .translation
-        .xy()
-        .distance_squared(anchor_transform.translation.xy())
-        .abs()
-        > anchor.radius * anchor.radius
-    {
-        commands
-            .entity(camera)
-            .insert(MoveTo::new_with_entity(
+    let left = targets
+        .get(anchored.target)
+        .is_none_or(|(target, target_transform)| {
+            target_transform
+                .translation
+                .xy()
+                .distance_squared(anchor_transform.translation.xy())
+                > anchor.radius * anchor.radius
+                || condition.is_some_and(|condition| !condition.allows(entity, target))
+        });
+
+    if left {
+        let mut camera = commands.entity(camera);
+        camera.remove::<DynamicallyAnchored>();
+        if targets.get(anchored.target).is_some() {
+            camera.insert(AnchorHandoff::new(
+                anchored.target,
                 Duration::from_millis(anchor.speed as u64),
-                camera_transform.translation,
-                target,
-                easing::EaseFunction::QuadraticOut,
-            ))
-            .remove::<DynamicallyAnchored>();
+            ));
+        }
     }
 }
 
-pub(crate) fn bind_to_dyn_anchor(
-    q: Query<(Entity, &DynamicCameraAnchor, &Transform)>,
-    target_transform: Single<&Transform, With<AnchorTarget>>,
-    camera: Single<(Entity, &Transform), (With<MainCamera>, Without<DynamicallyAnchored>)>,
+pub fn bind_to_dyn_anchor(
+    q: Query<
+        (
+            EntityRef,
+            &DynamicCameraAnchor,
+            &Transform,
+            Option<&AnchorCondition>,
+        ),
+        Without<MainCamera>,
+    >,
+    targets: AnchorTargets,
+    camera: Query<(Entity, &Transform), (With<MainCamera>, Without<DynamicallyAnchored>)>,
     mut commands: Commands,
 ) {
-    let (camera, camera_transform) = camera.into_inner();
-
-    for (entity, anchor, transform) in q.iter() {
-        if transform
-            .translation
-            .xy()
-            .distance_squared(target_transform.translation.xy())
-            .abs()
-            <= anchor.radius * anchor.radius
-        {
-            commands.entity(camera).insert((
-                MoveTo::new(
-                    Duration::from_millis(anchor.speed as u64),
-                    camera_transform.translation,
+    let Some((camera, camera_transform)) = single_or_warn(camera.single(), "MainCamera") else {
+        return;
+    };
+
+    for (entity, anchor, transform, condition) in q.iter() {
+        let Some((target, target_transform)) =
+            targets.iter(anchor).find(|(target, target_transform)| {
+                transform
+                    .translation
+                    .xy()
+                    .distance_squared(target_transform.translation.xy())
+                    <= anchor.radius * anchor.radius
+                    && condition.is_none_or(|condition| condition.allows(entity, *target))
+            })
+        else {
+            continue;
+        };
+
+        commands.entity(camera).remove::<AnchorHandoff>().insert((
+            MoveTo::new(
+                Duration::from_millis(anchor.speed as u64),
+                camera_transform.translation,
+                masked(
                     transform.translation,
-                    easing::EaseFunction::QuadraticOut,
+                    target_transform.translation,
+                    anchor.axes,
                 ),
-                DynamicallyAnchored(entity),
-            ));
+                anchor.easing,
+            ),
+            DynamicallyAnchored {
+                anchor: entity.id(),
+                target: target.id(),
+            },
+        ));
+        return;
+    }
+}
+
+/// Returns the [`MainCamera`] to its [`AnchorTarget`] after leaving a [`DynamicCameraAnchor`].
+///
+/// Follows the target with a critically damped spring, which converges regardless of how fast the
+/// target is moving, then binds the camera to the target with [`Binded`].
+#[derive(Debug, Clone, Copy, Component)]
+pub struct AnchorHandoff {
+    target: Entity,
+    smooth_time: f32,
+    velocity: Vec2,
+}
+
+impl AnchorHandoff {
+    /// Settles on a stationary target in roughly `duration`.
+    pub fn new(target: Entity, duration: Duration) -> Self {
+        Self {
+            target,
+            smooth_time: duration.as_secs_f32() / 3.,
+            velocity: Vec2::ZERO,
         }
     }
 }
+
+/// Distance under which an [`AnchorHandoff`] binds to its target.
+const HANDOFF_EPSILON: f32 = 0.5;
+
+pub fn anchor_handoff(
+    camera: Option<
+        Single<
+            (Entity, &mut Transform, &mut AnchorHandoff, Has<Binded>),
+            (With<MainCamera>, Without<MoveTo>),
+        >,
+    >,
+    targets: Query<(&Transform, Option<&ResolvedCameraOffset>), Without<MainCamera>>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let Some((camera, mut transform, mut handoff, binded)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    let Some((target, offset)) = targets.get(handoff.target).ok().filter(|_| !binded) else {
+        commands.entity(camera).remove::<AnchorHandoff>();
+        return;
+    };
+
+    let target = target.translation.xy() + offset.map(|o| o.0).unwrap_or_default();
+    let smooth_time = handoff.smooth_time;
+    let position = smooth_damp(
+        transform.translation.xy(),
+        target,
+        &mut handoff.velocity,
+        smooth_time,
+        time.delta_secs(),
+    );
+    transform.translation = position.extend(transform.translation.z);
+
+    if position.distance_squared(target) < HANDOFF_EPSILON * HANDOFF_EPSILON {
+        transform.translation = target.extend(transform.translation.z);
+        commands
+            .entity(camera)
+            .remove::<AnchorHandoff>()
+            .insert(Binded(handoff.target));
+    }
+}
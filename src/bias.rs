@@ -0,0 +1,317 @@
+use crate::accessibility::{ReducedMotion, motion_factor};
+use crate::camera::{Binded, MainCamera};
+use crate::pixel_perfect::{MainCanvas, OuterCamera};
+use crate::smoothing::decay;
+use bevy::input::gamepad::{Gamepad, GamepadAxis};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Shifts the [`MainCamera`] toward the cursor, on top of whatever is positioning the camera.
+///
+/// The offset is the cursor's distance from the center of the view, in canvas pixels, scaled by
+/// `strength` and limited to `max_offset`.
+#[derive(Debug, Clone, Copy, Component)]
+#[require(BiasOffset)]
+pub struct CursorBias {
+    pub strength: f32,
+    pub max_offset: f32,
+}
+
+impl CursorBias {
+    pub fn new(strength: f32, max_offset: f32) -> Self {
+        Self {
+            strength,
+            max_offset,
+        }
+    }
+}
+
+/// Temporarily offsets the [`MainCamera`] with an axis input, recentering once the input is
+/// released.
+///
+/// Write to [`PeekInput::input`] every frame, or read a gamepad stick with
+/// [`PeekInput::with_gamepad_axes`].
+#[derive(Debug, Clone, Copy, Component)]
+#[require(BiasOffset)]
+pub struct PeekInput {
+    /// The input for this frame, in the range `[-1, 1]` on each axis.
+    ///
+    /// Consumed every frame.
+    pub input: Vec2,
+    /// The gamepad axes read into `input`.
+    pub gamepad_axes: Option<(GamepadAxis, GamepadAxis)>,
+    /// The offset when `input` has a length of 1.
+    pub max_offset: f32,
+    /// Inputs with a length below `deadzone` are ignored.
+    pub deadzone: f32,
+    /// The rate at which the camera approaches the peeked offset.
+    pub smoothing: f32,
+    /// The rate at which the camera returns to center once the input is released.
+    pub recenter: f32,
+    current: Vec2,
+}
+
+impl PeekInput {
+    pub fn new(max_offset: f32) -> Self {
+        Self {
+            input: Vec2::ZERO,
+            gamepad_axes: None,
+            max_offset,
+            deadzone: 0.15,
+            smoothing: 8.,
+            recenter: 4.,
+            current: Vec2::ZERO,
+        }
+    }
+
+    /// Reads the right stick of the first gamepad with an input outside of the deadzone.
+    pub fn right_stick(max_offset: f32) -> Self {
+        Self::new(max_offset).with_gamepad_axes(GamepadAxis::RightStickX, GamepadAxis::RightStickY)
+    }
+
+    pub fn with_gamepad_axes(mut self, x: GamepadAxis, y: GamepadAxis) -> Self {
+        self.gamepad_axes = Some((x, y));
+        self
+    }
+
+    pub fn with_deadzone(mut self, deadzone: f32) -> Self {
+        self.deadzone = deadzone;
+        self
+    }
+
+    pub fn with_smoothing(mut self, smoothing: f32, recenter: f32) -> Self {
+        self.smoothing = smoothing;
+        self.recenter = recenter;
+        self
+    }
+
+    /// The current peek offset in world units.
+    pub fn offset(&self) -> Vec2 {
+        self.current * self.max_offset
+    }
+}
+
+/// The offset applied to the camera this frame by the bias systems.
+///
+/// Applied during [`PostUpdate`] and removed in [`PreUpdate`], just like [`Shake`](crate::shake::Shake).
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct BiasOffset(Vec2);
+
+impl BiasOffset {
+    pub fn offset(&self) -> Vec2 {
+        self.0
+    }
+
+    /// Adds to the offset, which is removed from the translation in [`PreUpdate`].
+    pub(crate) fn push(&mut self, offset: Vec2) {
+        self.0 += offset;
+    }
+}
+
+/// Returns the cursor's position relative to the center of the view in canvas pixels.
+fn cursor_view_offset(
+    window: &Window,
+    outer: Option<(&Camera, &GlobalTransform)>,
+    canvas: Option<&GlobalTransform>,
+    camera: (&Camera, &GlobalTransform),
+) -> Option<Vec2> {
+    let cursor = window.cursor_position()?;
+
+    match (outer, canvas) {
+        (Some((outer, outer_t)), Some(canvas_t)) => {
+            let world = outer.viewport_to_world_2d(outer_t, cursor).ok()?;
+            Some(
+                canvas_t
+                    .affine()
+                    .inverse()
+                    .transform_point3(world.extend(0.))
+                    .xy(),
+            )
+        }
+        _ => {
+            let (camera, camera_t) = camera;
+            let world = camera.viewport_to_world_2d(camera_t, cursor).ok()?;
+            Some(world - camera_t.translation().xy())
+        }
+    }
+}
+
+pub(crate) fn cursor_bias(
+    window: Option<Single<&Window, With<PrimaryWindow>>>,
+    outer: Option<Single<(&Camera, &GlobalTransform), (With<OuterCamera>, Without<MainCamera>)>>,
+    canvas: Option<Single<&GlobalTransform, With<MainCanvas>>>,
+    camera: Option<
+        Single<
+            (
+                &mut Transform,
+                &mut BiasOffset,
+                &CursorBias,
+                &Camera,
+                &GlobalTransform,
+            ),
+            With<MainCamera>,
+        >,
+    >,
+) {
+    let (Some(window), Some(camera)) = (window, camera) else {
+        return;
+    };
+    let (mut transform, mut offset, bias, camera, camera_t) = camera.into_inner();
+
+    let Some(cursor) = cursor_view_offset(
+        &window,
+        outer.as_deref().map(|(c, t)| (*c, *t)),
+        canvas.as_deref().copied(),
+        (camera, camera_t),
+    ) else {
+        return;
+    };
+
+    let bias = (cursor * bias.strength).clamp_length_max(bias.max_offset);
+    transform.translation += bias.extend(0.);
+    offset.0 += bias;
+}
+
+pub(crate) fn peek_input(
+    camera: Option<Single<(&mut Transform, &mut BiasOffset, &mut PeekInput), With<MainCamera>>>,
+    gamepads: Query<&Gamepad>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut offset, mut peek)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    if let Some((x, y)) = peek.gamepad_axes {
+        let deadzone = peek.deadzone;
+        if let Some(stick) = gamepads
+            .iter()
+            .map(|gamepad| Vec2::new(gamepad.get(x).unwrap_or(0.), gamepad.get(y).unwrap_or(0.)))
+            .find(|stick| stick.length() >= deadzone)
+        {
+            peek.input = stick;
+        }
+    }
+
+    let input = core::mem::take(&mut peek.input).clamp_length_max(1.);
+    let (target, rate) = if input.length() < peek.deadzone {
+        (Vec2::ZERO, peek.recenter)
+    } else {
+        (input, peek.smoothing)
+    };
+
+    peek.current = decay(peek.current, target, rate, time.delta_secs());
+
+    let peek = peek.offset() * motion_factor(reduced_motion.as_deref());
+    transform.translation += peek.extend(0.);
+    offset.0 += peek;
+}
+
+/// Gently drifts the [`MainCamera`] with smooth noise once the [`Binded`] target, or the camera
+/// itself when unbound, has been still for `delay` seconds.
+///
+/// Applied on top of the camera's position like the [`CursorBias`], so it composes with shake.
+#[derive(Debug, Clone, Copy, Component)]
+#[require(BiasOffset)]
+pub struct IdleDrift {
+    /// The largest offset of the drift.
+    pub amplitude: f32,
+    /// How quickly the noise changes, in cycles per second.
+    pub frequency: f32,
+    /// Seconds of stillness before the drift fades in.
+    pub delay: f32,
+    /// The rate at which the drift fades in and out.
+    pub fade: f32,
+    idle: f32,
+    weight: f32,
+    last: Option<Vec2>,
+}
+
+impl IdleDrift {
+    pub fn new(amplitude: f32, frequency: f32) -> Self {
+        Self {
+            amplitude,
+            frequency,
+            delay: 2.,
+            fade: 1.,
+            idle: 0.,
+            weight: 0.,
+            last: None,
+        }
+    }
+
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+impl Default for IdleDrift {
+    fn default() -> Self {
+        Self::new(2., 0.1)
+    }
+}
+
+/// Distance under which the drift's subject is considered still.
+const IDLE_EPSILON: f32 = 0.01;
+
+pub(crate) fn idle_drift(
+    camera: Option<
+        Single<
+            (
+                &mut Transform,
+                &mut BiasOffset,
+                &mut IdleDrift,
+                Option<&Binded>,
+            ),
+            With<MainCamera>,
+        >,
+    >,
+    targets: Query<&GlobalTransform, Without<MainCamera>>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut offset, mut drift, binded)) = camera.map(|c| c.into_inner())
+    else {
+        return;
+    };
+
+    let position = binded
+        .and_then(|binded| targets.get(binded.0).ok())
+        .map(|target| target.translation().xy())
+        .unwrap_or(transform.translation.xy() - offset.0);
+
+    let still = drift
+        .last
+        .is_some_and(|last| last.distance_squared(position) < IDLE_EPSILON * IDLE_EPSILON);
+    drift.idle = if still {
+        drift.idle + time.delta_secs()
+    } else {
+        0.
+    };
+    drift.last = Some(position);
+
+    let target = if drift.idle >= drift.delay { 1. } else { 0. };
+    drift.weight = decay(drift.weight, target, drift.fade, time.delta_secs());
+    if drift.weight < 1e-3 {
+        return;
+    }
+
+    let t = time.elapsed_secs() * drift.frequency;
+    let noise = Vec2::new(
+        noise::fbm_simplex_2d(Vec2::new(t, 3.), 2, 2., 0.5),
+        noise::fbm_simplex_2d(Vec2::new(t, 4.), 2, 2., 0.5),
+    );
+    let bias = noise * drift.amplitude * drift.weight * motion_factor(reduced_motion.as_deref());
+    transform.translation += bias.extend(0.);
+    offset.0 += bias;
+}
+
+pub(crate) fn restore_bias(mut biased: Query<(&mut Transform, &mut BiasOffset)>) {
+    for (mut transform, mut offset) in biased.iter_mut() {
+        if offset.0 != Vec2::ZERO {
+            transform.translation -= offset.0.extend(0.);
+            offset.0 = Vec2::ZERO;
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::bias::BiasOffset;
+use crate::camera::MainCamera;
+use crate::smoothing::decay;
+use crate::visible::VisibleWorldRect;
+use bevy::prelude::*;
+
+/// Confines the view of the [`MainCamera`] to `rect`.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct CameraBounds {
+    pub rect: Rect,
+}
+
+impl CameraBounds {
+    pub fn new(rect: Rect) -> Self {
+        Self { rect }
+    }
+}
+
+pub(crate) fn clamp_to_bounds(
+    camera: Option<Single<(&mut Transform, &CameraBounds), With<MainCamera>>>,
+    visible: VisibleWorldRect,
+) {
+    let Some((mut transform, bounds)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    let Some(region) = visible.camera_region(bounds.rect) else {
+        return;
+    };
+
+    let clamped = transform.translation.xy().clamp(region.min, region.max);
+    transform.translation = clamped.extend(transform.translation.z);
+}
+
+/// A region, relative to the entity's translation, that the view of a [`MainCamera`] with
+/// [`AvoidObstacles`] is pushed out of.
+///
+/// Useful for areas reserved by UI, or spoilers that should stay off screen.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+#[require(Transform)]
+pub struct CameraObstacle {
+    pub rect: Rect,
+}
+
+impl CameraObstacle {
+    pub fn new(size: Vec2) -> Self {
+        Self {
+            rect: Rect::from_center_size(Vec2::ZERO, size),
+        }
+    }
+}
+
+/// Smoothly pushes the view of the [`MainCamera`] out of every [`CameraObstacle`].
+///
+/// The push is applied on top of whatever is positioning the camera, like the
+/// [`CursorBias`](crate::bias::CursorBias).
+#[derive(Debug, Clone, Copy, Component)]
+#[require(BiasOffset)]
+pub struct AvoidObstacles {
+    /// The rate at which the camera approaches the resolved position.
+    pub rate: f32,
+    current: Vec2,
+}
+
+impl AvoidObstacles {
+    pub fn new(rate: f32) -> Self {
+        Self {
+            rate,
+            current: Vec2::ZERO,
+        }
+    }
+}
+
+impl Default for AvoidObstacles {
+    fn default() -> Self {
+        Self::new(10.)
+    }
+}
+
+/// The shortest offset that moves `point` outside of `rect`.
+fn push_out(point: Vec2, rect: Rect) -> Vec2 {
+    if !rect.contains(point) {
+        return Vec2::ZERO;
+    }
+
+    [
+        Vec2::new(rect.min.x - point.x, 0.),
+        Vec2::new(rect.max.x - point.x, 0.),
+        Vec2::new(0., rect.min.y - point.y),
+        Vec2::new(0., rect.max.y - point.y),
+    ]
+    .into_iter()
+    .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+    .unwrap_or_default()
+}
+
+pub(crate) fn avoid_obstacles(
+    camera: Option<
+        Single<(&mut Transform, &mut BiasOffset, &mut AvoidObstacles), With<MainCamera>>,
+    >,
+    obstacles: Query<(&GlobalTransform, &CameraObstacle)>,
+    visible: VisibleWorldRect,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut offset, mut avoid)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+    let Some(view) = visible.size() else {
+        return;
+    };
+
+    let mut center = transform.translation.xy();
+    let start = center;
+    for (obstacle_transform, obstacle) in obstacles.iter() {
+        // The region in which the camera's center would see into the obstacle.
+        let origin = obstacle_transform.translation().xy();
+        let rect = Rect {
+            min: origin + obstacle.rect.min - view / 2.,
+            max: origin + obstacle.rect.max + view / 2.,
+        };
+        center += push_out(center, rect);
+    }
+
+    avoid.current = decay(avoid.current, center - start, avoid.rate, time.delta_secs());
+    transform.translation += avoid.current.extend(0.);
+    offset.push(avoid.current);
+}
+
+#[cfg(feature = "sequence")]
+use crate::anchor::{AnchorCondition, set_anchor_condition};
+#[cfg(feature = "sequence")]
+use bevy_sequence::prelude::*;
+
+#[cfg(feature = "sequence")]
+pub trait CameraConstraintFragment<D, C>: Sized
+where
+    D: Threaded,
+    C: Clone,
+{
+    /// Confines the camera to `rect` with [`CameraBounds`].
+    fn set_camera_bounds(self, rect: Rect) -> impl IntoFragment<D, C>;
+
+    /// Removes the camera's [`CameraBounds`].
+    fn clear_camera_bounds(self) -> impl IntoFragment<D, C>;
+
+    /// Allows the anchors marked with `M` to capture the camera.
+    fn enable_anchor<M: Component>(self) -> impl IntoFragment<D, C>;
+
+    /// Prevents the anchors marked with `M` from capturing the camera with
+    /// [`AnchorCondition::Disabled`].
+    fn disable_anchor<M: Component>(self) -> impl IntoFragment<D, C>;
+}
+
+#[cfg(feature = "sequence")]
+impl<D, C, T> CameraConstraintFragment<D, C> for T
+where
+    Self: IntoFragment<D, C>,
+    D: Threaded,
+    C: Threaded + Clone,
+{
+    fn set_camera_bounds(self, rect: Rect) -> impl IntoFragment<D, C> {
+        let system = move |camera: Single<Entity, With<MainCamera>>, mut commands: Commands| {
+            commands
+                .entity(camera.into_inner())
+                .insert(CameraBounds::new(rect));
+        };
+
+        self.on_start(system)
+    }
+
+    fn clear_camera_bounds(self) -> impl IntoFragment<D, C> {
+        let system = |camera: Single<Entity, With<MainCamera>>, mut commands: Commands| {
+            commands
+                .entity(camera.into_inner())
+                .remove::<CameraBounds>();
+        };
+
+        self.on_start(system)
+    }
+
+    fn enable_anchor<M: Component>(self) -> impl IntoFragment<D, C> {
+        self.on_start(set_anchor_condition::<M>(AnchorCondition::AlwaysOn))
+    }
+
+    fn disable_anchor<M: Component>(self) -> impl IntoFragment<D, C> {
+        self.on_start(set_anchor_condition::<M>(AnchorCondition::Disabled))
+    }
+}
@@ -0,0 +1,67 @@
+use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use bevy::asset::weak_handle;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_resource::ShaderRef;
+use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
+use serde::Deserialize;
+
+pub const DISPLAY_CALIBRATION_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("a4c61e2d-93f7-4b08-b5d2-6e1f8c3a7d95");
+
+pub struct DisplayCalibrationPlugin;
+
+impl Plugin for DisplayCalibrationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DisplayCalibrationSettings>()
+            .add_plugins(PostProcessPlugin::<DisplayCalibrationSettings>::default());
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
+
+        load_internal_asset!(
+            app,
+            DISPLAY_CALIBRATION_SHADER_HANDLE,
+            "shaders/calibration.wgsl",
+            Shader::from_wgsl
+        );
+    }
+}
+
+/// The player's display settings, e.g. from a video settings menu.
+///
+/// Runs as a [`PostProcessMaterial::final_pass`], after every other optix effect, so that the
+/// calibration applies to the final image.
+#[derive(Debug, Component, Clone, Copy, ExtractComponent, ShaderType, Reflect, Deserialize)]
+#[serde(default)]
+pub struct DisplayCalibrationSettings {
+    /// The display gamma, where `1` is neutral and higher values brighten the mid tones.
+    pub gamma: f32,
+    /// An offset added to every channel, where `0` is neutral.
+    pub brightness: f32,
+    /// A scale around mid gray, where `1` is neutral.
+    pub contrast: f32,
+    /// A scale away from gray, where `0` is grayscale and `1` is neutral.
+    pub saturation: f32,
+}
+
+impl Default for DisplayCalibrationSettings {
+    fn default() -> Self {
+        Self {
+            gamma: 1.,
+            brightness: 0.,
+            contrast: 1.,
+            saturation: 1.,
+        }
+    }
+}
+
+impl PostProcessMaterial for DisplayCalibrationSettings {
+    fn fragment_shader() -> ShaderRef {
+        DISPLAY_CALIBRATION_SHADER_HANDLE.into()
+    }
+
+    fn final_pass() -> bool {
+        true
+    }
+}
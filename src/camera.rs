@@ -1,46 +1,286 @@
+use crate::anchor::{AnchorHandoff, AnchorPlugin, AnchorSystem};
+use crate::constraints::CameraConstraints;
+use crate::ease::OptixEase;
+use crate::kinematics::CameraKinematics;
+use crate::replay::{OptixCommand, record_command};
+use crate::shake::{AddTrauma, ShakeSystem};
+use crate::visible::VisibleWorldRect;
 use bevy::ecs::component::HookContext;
+use bevy::ecs::query::QuerySingleError;
+use bevy::ecs::schedule::ScheduleLabel;
 use bevy::ecs::world::DeferredWorld;
 use bevy::prelude::*;
+use bevy::render::view::VisibilitySystems;
+use std::collections::VecDeque;
 use std::time::Duration;
 
-#[derive(Debug, Clone, Copy, Component)]
+#[derive(Debug, Default, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
 #[require(PixelSnap)]
 pub struct MainCamera;
 
+/// The single item of a query for a uniquely marked entity, such as the [`MainCamera`].
+///
+/// Missing entities are expected, e.g. while a level loads or after the camera is despawned, and
+/// return `None` silently. Duplicates return `None` with a warning, naming the entity by `name`.
+pub(crate) fn single_or_warn<T>(result: Result<T, QuerySingleError>, name: &str) -> Option<T> {
+    match result {
+        Ok(item) => Some(item),
+        Err(QuerySingleError::NoEntities(_)) => None,
+        Err(QuerySingleError::MultipleEntities(_)) => {
+            warn_once!("found multiple {name}s, systems that expect exactly one are skipped");
+            None
+        }
+    }
+}
+
+/// A [`MainCamera`] setup that can be spawned from a [`DynamicScene`].
+///
+/// Pair with [`BindedByName`] to bind the camera to an entity spawned by the same scene.
+#[derive(Debug, Default, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
+#[require(Camera2d, MainCamera, Transform)]
+pub struct CameraRig;
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
 pub enum CameraSystem {
     UpdateCamera,
     SnapToGrid,
 }
 
-pub struct CameraAnimationPlugin;
+/// Moves the [`MainCamera`] with bindings, anchors, [`MoveTo`]s, and the rest of the camera
+/// systems.
+///
+/// When several of them drive the camera at once, [`MoveTo`]s take priority over
+/// [`CameraTimeline`]s, then [`VirtualCamera`]s, then [`FrameGroup`]s, and finally bindings and
+/// other followers.
+///
+/// [`CameraTimeline`]: crate::timeline::CameraTimeline
+/// [`VirtualCamera`]: crate::virtual_camera::VirtualCamera
+/// [`FrameGroup`]: crate::framing::FrameGroup
+#[derive(Default)]
+pub struct CameraAnimationPlugin {
+    pub mode: CameraUpdateMode,
+}
+
+impl CameraAnimationPlugin {
+    /// Runs the camera's follow logic on the fixed timestep, see [`CameraUpdateMode::Fixed`].
+    pub fn fixed() -> Self {
+        Self {
+            mode: CameraUpdateMode::Fixed,
+        }
+    }
+}
+
+/// When the [`MainCamera`]'s follow logic runs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CameraUpdateMode {
+    /// Runs every frame in [`PostUpdate`].
+    #[default]
+    Variable,
+    /// Runs bindings, anchors, [`MoveTo`]s, rooms, and bounds in [`FixedPostUpdate`], for games
+    /// whose simulation runs on a fixed timestep.
+    ///
+    /// The camera's translation is interpolated between fixed steps in [`PostUpdate`], where the
+    /// view effects, such as biases and zoom punches, are applied on top. The translation is
+    /// overwritten by the next fixed step, so the camera should only be moved by fixed systems.
+    ///
+    /// Other entities moved by a [`MoveTo`] are not interpolated.
+    Fixed,
+}
+
+/// Orders the follow logic, which may run on the fixed timestep, before the view effects.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+enum CameraStage {
+    Follow,
+    Effects,
+}
 
 impl Plugin for CameraAnimationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(First, release_snap)
+        let follow_schedule = match self.mode {
+            CameraUpdateMode::Variable => PostUpdate.intern(),
+            CameraUpdateMode::Fixed => FixedPostUpdate.intern(),
+        };
+
+        app.register_type::<MainCamera>()
+            .register_type::<CameraRig>()
+            .register_type::<Binded>()
+            .register_type::<BindedByName>()
+            .register_type::<BindedMode>()
+            .register_type::<BindSmoothingWindow>()
+            .register_type::<AimFraming>()
+            .register_type::<SpringFollow>()
+            .register_type::<CameraOffset>()
+            .register_type::<CameraOffsetMode>()
+            .register_type::<PixelSnap>()
+            .register_type::<SnapThreshold>()
+            .register_type::<TeleportResponse>()
+            // timelines add trauma without requiring the `ScreenShakePlugin`
+            .add_event::<AddTrauma>()
+            .init_resource::<crate::framing::FadingFrameWeights>()
+            .add_systems(First, release_snap)
             .add_systems(
-                PostUpdate,
+                PreUpdate,
+                // shakes restore an absolute translation that includes the view effects, and the
+                // punch is removed before the follow schedule, where `zoom_to` writes the absolute
+                // scale
+                (crate::bias::restore_bias, crate::zoom::restore_zoom_punch)
+                    .after(ShakeSystem::Restore),
+            )
+            .add_systems(
+                follow_schedule,
                 (
+                    resolve_camera_offsets.before(AnchorSystem::Bind),
+                    // each of these may write the camera's translation, so they run in
+                    // order of priority, the last one winning, after the anchor bindings and
+                    // handoffs, which have the lowest priority
                     (
                         (
-                            crate::anchor::bind_to_dyn_anchor,
-                            crate::anchor::unbind_dyn_anchor,
                             camera_binded,
-                            camera_move_to,
-                        ),
-                        crate::anchor::anchor,
+                            camera_spring_follow,
+                            crate::networked::camera_networked_follow,
+                        )
+                            .chain(),
+                        (
+                            crate::framing::ramp_frame_weights,
+                            crate::framing::frame_group,
+                        )
+                            .chain(),
+                        crate::virtual_camera::direct_virtual_cameras,
+                        crate::timeline::play_timeline
+                            .run_if(resource_exists::<Assets<crate::timeline::CameraTimeline>>),
+                        (move_to, crate::zoom::zoom_to),
                     )
                         .chain()
-                        .before(TransformSystem::TransformPropagate)
-                        .in_set(CameraSystem::UpdateCamera),
+                        .after(AnchorSystem::Bind)
+                        .before(AnchorSystem::Follow),
+                    (
+                        (crate::rooms::enter_room, crate::rooms::clamp_to_room).chain(),
+                        crate::bounds::clamp_to_bounds,
+                        crate::constraints::apply_camera_constraints,
+                        crate::kinematics::limit_kinematics,
+                    )
+                        .chain()
+                        .after(AnchorSystem::Follow),
+                )
+                    .in_set(CameraStage::Follow),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    resolve_binded_by_name.before(CameraSystem::UpdateCamera),
+                    (
+                        crate::bounds::avoid_obstacles,
+                        crate::bias::cursor_bias,
+                        crate::bias::peek_input,
+                        crate::bias::idle_drift,
+                        crate::recoil::camera_recoil,
+                        crate::zoom::zoom_punch,
+                    )
+                        .chain()
+                        .in_set(CameraStage::Effects),
                     snap.before(TransformSystem::TransformPropagate)
                         .in_set(CameraSystem::SnapToGrid),
+                    crate::visible::cull_far_from_camera
+                        .after(TransformSystem::TransformPropagate)
+                        .before(VisibilitySystems::VisibilityPropagate),
+                ),
+            )
+            .configure_sets(
+                follow_schedule,
+                (
+                    CameraStage::Follow.in_set(CameraSystem::UpdateCamera),
+                    (AnchorSystem::Bind, AnchorSystem::Follow)
+                        .chain()
+                        .in_set(CameraStage::Follow),
                 ),
             )
             .configure_sets(
                 PostUpdate,
-                CameraSystem::UpdateCamera.before(CameraSystem::SnapToGrid),
+                (
+                    CameraSystem::UpdateCamera
+                        .before(CameraSystem::SnapToGrid)
+                        .before(TransformSystem::TransformPropagate),
+                    CameraStage::Effects
+                        .after(CameraStage::Follow)
+                        .in_set(CameraSystem::UpdateCamera),
+                    ShakeSystem::Shake.after(CameraStage::Effects),
+                ),
+            );
+
+        if self.mode == CameraUpdateMode::Fixed {
+            app.add_systems(
+                FixedPostUpdate,
+                (
+                    begin_fixed_step.before(CameraStage::Follow),
+                    end_fixed_step.after(CameraStage::Follow),
+                )
+                    .in_set(CameraSystem::UpdateCamera),
+            )
+            .add_systems(
+                PostUpdate,
+                interpolate_fixed_step
+                    .before(CameraStage::Effects)
+                    .in_set(CameraSystem::UpdateCamera),
             );
+        }
+
+        if !app.is_plugin_added::<AnchorPlugin>() {
+            app.add_plugins(AnchorPlugin {
+                schedule: follow_schedule,
+                ..Default::default()
+            });
+        }
+    }
+}
+
+/// The [`MainCamera`]'s translation at the last two fixed steps in [`CameraUpdateMode::Fixed`].
+#[derive(Debug, Clone, Copy, Component)]
+struct FixedStepTranslation {
+    previous: Vec3,
+    current: Vec3,
+}
+
+fn begin_fixed_step(
+    mut commands: Commands,
+    camera: Option<
+        Single<(Entity, &mut Transform, Option<&mut FixedStepTranslation>), With<MainCamera>>,
+    >,
+) {
+    let Some((entity, mut transform, fixed)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    match fixed {
+        Some(mut fixed) => {
+            // discard the interpolation
+            transform.translation = fixed.current;
+            fixed.previous = fixed.current;
+        }
+        None => {
+            commands.entity(entity).insert(FixedStepTranslation {
+                previous: transform.translation,
+                current: transform.translation,
+            });
+        }
+    }
+}
+
+fn end_fixed_step(
+    camera: Option<Single<(&Transform, &mut FixedStepTranslation), With<MainCamera>>>,
+) {
+    if let Some((transform, mut fixed)) = camera.map(|c| c.into_inner()) {
+        fixed.current = transform.translation;
+    }
+}
+
+fn interpolate_fixed_step(
+    camera: Option<Single<(&mut Transform, &FixedStepTranslation), With<MainCamera>>>,
+    time: Res<Time<Fixed>>,
+) {
+    if let Some((mut transform, fixed)) = camera.map(|c| c.into_inner()) {
+        transform.translation = fixed.previous.lerp(fixed.current, time.overstep_fraction());
     }
 }
 
@@ -69,7 +309,7 @@ where
         _marker: M,
         offset: Vec2,
         duration: Duration,
-        curve: EaseFunction,
+        curve: impl Into<OptixEase>,
     ) -> impl IntoFragment<D, C>;
 
     /// Bind the camera to an entity's position.
@@ -91,7 +331,7 @@ where
         _marker: M,
         offset: Vec2,
         duration: Duration,
-        curve: EaseFunction,
+        curve: impl Into<OptixEase>,
     ) -> impl IntoFragment<D, C>;
 }
 
@@ -108,17 +348,16 @@ where
         offset: Vec2,
         duration: Duration,
     ) -> impl IntoFragment<D, C> {
-        let system = move |camera: Single<(Entity, &Transform), With<MainCamera>>,
+        let system = move |camera: Single<Entity, With<MainCamera>>,
                            entity_t: Single<&Transform, With<M>>,
                            mut commands: Commands| {
-            let (camera, camera_t) = camera.into_inner();
-            commands.entity(camera).insert(MoveTo::new(
-                duration,
-                camera_t.translation,
-                entity_t.translation + offset.extend(0.),
-                EaseFunction::Linear,
-            ));
-            commands.entity(camera).remove::<Binded>();
+            commands
+                .entity(camera.into_inner())
+                .insert(MoveTo::from_current(
+                    entity_t.translation.xy() + offset,
+                    duration,
+                    EaseFunction::Linear,
+                ));
         };
 
         self.on_start(system)
@@ -129,23 +368,24 @@ where
         _marker: M,
         offset: Vec2,
         duration: Duration,
-        curve: EaseFunction,
+        curve: impl Into<OptixEase>,
     ) -> impl IntoFragment<D, C> {
-        let system = move |camera: Single<(Entity, &Transform), With<MainCamera>>,
-                           entity_t: Single<(&Transform, Option<&CameraOffset>), With<M>>,
-                           mut commands: Commands| {
-            let (entity_t, entity_offset) = entity_t.into_inner();
-            let (camera, camera_t) = camera.into_inner();
-            commands.entity(camera).insert(MoveTo::new(
-                duration,
-                camera_t.translation,
-                entity_t.translation
-                    + offset.extend(0.)
-                    + entity_offset.map(|o| o.0).unwrap_or_default().extend(0.),
-                curve,
-            ));
-            commands.entity(camera).remove::<Binded>();
-        };
+        let curve = curve.into();
+        let system =
+            move |camera: Single<Entity, With<MainCamera>>,
+                  entity_t: Single<(&Transform, Option<&ResolvedCameraOffset>), With<M>>,
+                  mut commands: Commands| {
+                let (entity_t, entity_offset) = entity_t.into_inner();
+                commands
+                    .entity(camera.into_inner())
+                    .insert(MoveTo::from_current(
+                        entity_t.translation.xy()
+                            + offset
+                            + entity_offset.map(|o| o.0).unwrap_or_default(),
+                        duration,
+                        curve,
+                    ));
+            };
 
         self.on_start(system)
     }
@@ -160,17 +400,16 @@ where
         offset: Vec2,
         duration: Duration,
     ) -> impl IntoFragment<D, C> {
-        let mov = move |camera: Single<(Entity, &Transform), With<MainCamera>>,
+        let mov = move |camera: Single<Entity, With<MainCamera>>,
                         entity_t: Single<&Transform, With<M>>,
                         mut commands: Commands| {
-            let (camera, camera_t) = camera.into_inner();
-            commands.entity(camera).insert(MoveTo::new(
-                duration,
-                camera_t.translation,
-                entity_t.translation + offset.extend(0.),
-                EaseFunction::Linear,
-            ));
-            commands.entity(camera).remove::<Binded>();
+            commands
+                .entity(camera.into_inner())
+                .insert(MoveTo::from_current(
+                    entity_t.translation.xy() + offset,
+                    duration,
+                    EaseFunction::Linear,
+                ));
         };
 
         self.on_start(mov).on_end(bind_camera::<M>)
@@ -181,45 +420,87 @@ where
         _marker: M,
         offset: Vec2,
         duration: Duration,
-        curve: EaseFunction,
+        curve: impl Into<OptixEase>,
     ) -> impl IntoFragment<D, C> {
-        let system = move |camera: Single<(Entity, &Transform), With<MainCamera>>,
-                           entity_t: Single<(&Transform, Option<&CameraOffset>), With<M>>,
-                           mut commands: Commands| {
-            let (entity_t, entity_offset) = entity_t.into_inner();
-            let (camera, camera_t) = camera.into_inner();
-            commands.entity(camera).insert(MoveTo::new(
-                duration,
-                camera_t.translation,
-                entity_t.translation
-                    + offset.extend(0.)
-                    + entity_offset.map(|o| o.0).unwrap_or_default().extend(0.),
-                curve,
-            ));
-        };
+        let curve = curve.into();
+        let system =
+            move |camera: Single<Entity, With<MainCamera>>,
+                  entity_t: Single<(&Transform, Option<&ResolvedCameraOffset>), With<M>>,
+                  mut commands: Commands| {
+                let (entity_t, entity_offset) = entity_t.into_inner();
+                commands
+                    .entity(camera.into_inner())
+                    .insert(MoveTo::from_current(
+                        entity_t.translation.xy()
+                            + offset
+                            + entity_offset.map(|o| o.0).unwrap_or_default(),
+                        duration,
+                        curve,
+                    ));
+            };
 
         self.on_start(system).on_end(bind_camera::<M>)
     }
 }
 
+/// Moves an entity's [`Transform`] to a position or another entity over a duration.
+///
+/// Works on any entity, so cutscenes can slide props and characters with the same API as the
+/// camera. The [`MainCamera`] additionally binds to its target entity once the move completes,
+/// and moves towards the target's [`CameraOffset`].
+///
+/// [`MoveTo::from_current`] starts from wherever the entity is when the move is inserted.
+///
+/// Moves run alongside the camera's follow logic, so in [`CameraUpdateMode::Fixed`] they only
+/// advance on fixed steps. Only the [`MainCamera`] is interpolated between them, so props moved
+/// this way visibly step at low fixed rates unless they are interpolated by the game.
 #[derive(Component)]
 #[component(on_insert = on_insert_moveto)]
 pub struct MoveTo {
     timer: Timer,
-    easing: EaseFunction,
+    easing: OptixEase,
     domain: Domain,
+    clamp: Option<Rect>,
+    zoom: Option<(f32, f32)>,
+    from_current: bool,
 }
 
+/// The easing applied to a [`MoveTo`].
+#[deprecated(note = "use `OptixEase`, which every transition accepts")]
+pub type MoveEasing = OptixEase;
+
 fn on_insert_moveto(mut world: DeferredWorld, context: HookContext) {
     world.commands().entity(context.entity).remove::<Binded>();
+
+    let current = world
+        .get::<Transform>(context.entity)
+        .map(|transform| transform.translation);
+    let Some(mut move_to) = world.get_mut::<MoveTo>(context.entity) else {
+        return;
+    };
+    if !std::mem::take(&mut move_to.from_current) {
+        return;
+    }
+
+    let current = current.unwrap_or_default();
+    match &mut move_to.domain {
+        Domain::Positions { start, end } => {
+            *start = current;
+            end.z = current.z;
+        }
+        Domain::Entity { start, .. } => *start = current,
+    }
 }
 
 impl MoveTo {
-    pub fn new(duration: Duration, start: Vec3, end: Vec3, easing: EaseFunction) -> Self {
+    pub fn new(duration: Duration, start: Vec3, end: Vec3, easing: impl Into<OptixEase>) -> Self {
         Self {
             timer: Timer::new(duration, TimerMode::Once),
-            easing,
+            easing: easing.into(),
             domain: Domain::Positions { start, end },
+            clamp: None,
+            zoom: None,
+            from_current: false,
         }
     }
 
@@ -227,12 +508,55 @@ impl MoveTo {
         duration: Duration,
         start: Vec3,
         target: Entity,
-        easing: EaseFunction,
+        easing: impl Into<OptixEase>,
     ) -> Self {
         Self {
             timer: Timer::new(duration, TimerMode::Once),
-            easing,
+            easing: easing.into(),
             domain: Domain::Entity { start, end: target },
+            clamp: None,
+            zoom: None,
+            from_current: false,
+        }
+    }
+
+    /// Moves from the entity's translation when inserted to `end`, keeping its z.
+    pub fn from_current(end: Vec2, duration: Duration, easing: impl Into<OptixEase>) -> Self {
+        Self {
+            from_current: true,
+            ..Self::new(duration, Vec3::ZERO, end.extend(0.), easing)
+        }
+    }
+
+    /// Moves from the entity's translation when inserted to `target`.
+    pub fn from_current_to_entity(
+        target: Entity,
+        duration: Duration,
+        easing: impl Into<OptixEase>,
+    ) -> Self {
+        Self {
+            from_current: true,
+            ..Self::new_with_entity(duration, Vec3::ZERO, target, easing)
+        }
+    }
+
+    /// Clamps the end of the move within `rect`.
+    pub fn clamped(mut self, rect: Rect) -> Self {
+        self.clamp = Some(rect);
+        self
+    }
+
+    /// Animates the orthographic scale from `start` to `end` alongside the move, sharing its timer
+    /// and easing so that the pan and zoom stay in sync.
+    pub fn with_zoom(mut self, start: f32, end: f32) -> Self {
+        self.zoom = Some((start, end));
+        self
+    }
+
+    fn clamp_end(&self, end: Vec3) -> Vec3 {
+        match self.clamp {
+            Some(rect) => end.xy().clamp(rect.min, rect.max).extend(end.z),
+            None => end,
         }
     }
 
@@ -241,7 +565,103 @@ impl MoveTo {
     }
 
     pub fn complete(&self) -> bool {
-        self.timer.finished()
+        // `finished` is only updated by `tick`
+        self.timer.elapsed() >= self.timer.duration()
+    }
+
+    /// The fraction of the move's duration that has elapsed, before easing.
+    pub fn fraction(&self) -> f32 {
+        self.timer.fraction()
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+
+    /// Jumps to `fraction` of the move's duration, e.g. `1.` to skip a cinematic move.
+    pub fn set_fraction(&mut self, fraction: f32) {
+        let elapsed = self.timer.duration().mul_f32(fraction.clamp(0., 1.));
+        self.timer.set_elapsed(elapsed);
+    }
+
+    pub fn pause(&mut self) {
+        self.timer.pause();
+    }
+
+    pub fn resume(&mut self) {
+        self.timer.unpause();
+    }
+
+    pub fn paused(&self) -> bool {
+        self.timer.paused()
+    }
+}
+
+pub trait CameraMoveCommands {
+    /// Stops the [`MainCamera`]'s active [`MoveTo`].
+    ///
+    /// Moves towards an entity blend back to it over `blend` and then bind, otherwise the camera
+    /// holds its position.
+    fn cancel_camera_move(&mut self, blend: Duration);
+
+    /// Moves the [`MainCamera`] from its current translation to `translation` with a [`MoveTo`].
+    fn move_camera_to(
+        &mut self,
+        translation: Vec2,
+        duration: Duration,
+        easing: impl Into<OptixEase>,
+    );
+}
+
+impl CameraMoveCommands for Commands<'_, '_> {
+    fn cancel_camera_move(&mut self, blend: Duration) {
+        self.queue(move |world: &mut World| -> Result {
+            record_command(world, OptixCommand::CancelMove { blend });
+            let camera = world
+                .query_filtered::<Entity, With<MainCamera>>()
+                .single(world)?;
+            let mut camera = world.entity_mut(camera);
+            let Some(move_to) = camera.take::<MoveTo>() else {
+                return Ok(());
+            };
+
+            match move_to.domain.target() {
+                Some(target) if blend.is_zero() => {
+                    camera.insert(Binded(target));
+                }
+                Some(target) => {
+                    camera.insert(AnchorHandoff::new(target, blend));
+                }
+                None => {}
+            }
+            Ok(())
+        });
+    }
+
+    fn move_camera_to(
+        &mut self,
+        translation: Vec2,
+        duration: Duration,
+        easing: impl Into<OptixEase>,
+    ) {
+        let easing = easing.into();
+        self.queue(move |world: &mut World| -> Result {
+            record_command(
+                world,
+                OptixCommand::MoveTo {
+                    translation,
+                    duration,
+                    easing,
+                },
+            );
+            let camera = world
+                .query_filtered::<Entity, With<MainCamera>>()
+                .single(world)?;
+            world
+                .entity_mut(camera)
+                .insert(MoveTo::from_current(translation, duration, easing));
+            Ok(())
+        });
     }
 }
 
@@ -259,12 +679,267 @@ impl Domain {
     }
 }
 
-#[derive(Debug, Clone, Copy, Component)]
-pub struct Binded(pub Entity);
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component)]
+pub struct Binded(#[entities] pub Entity);
+
+/// Copies more of a [`Binded`] target's [`Transform`] than its translation.
+///
+/// Intended for cameras attached to rotating vehicles, or bosses that scale up during an intro.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct BindedMode {
+    /// Copies the target's rotation, rotating the [`CameraOffset`] along with it.
+    pub rotation: bool,
+    /// Copies the target's scale, scaling the camera's view.
+    pub scale: bool,
+}
+
+impl BindedMode {
+    pub const TRANSLATION: Self = Self {
+        rotation: false,
+        scale: false,
+    };
+    pub const ALL: Self = Self {
+        rotation: true,
+        scale: true,
+    };
+
+    pub fn with_rotation(mut self) -> Self {
+        self.rotation = true;
+        self
+    }
+
+    pub fn with_scale(mut self) -> Self {
+        self.scale = true;
+        self
+    }
+}
+
+/// Frames a [`Binded`] camera on a weighted point between its target and an aim point, such as a
+/// crosshair or the cursor, as in most top-down shooters.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct AimFraming {
+    /// The entity being aimed at.
+    #[entities]
+    pub aim: Entity,
+    /// How strongly the camera favors the bound target over the aim point, in the range `[0, 1]`.
+    pub target_weight: f32,
+    /// The farthest the camera strays from the bound target, in world units.
+    pub max_distance: f32,
+}
+
+impl AimFraming {
+    pub fn new(aim: Entity) -> Self {
+        Self {
+            aim,
+            target_weight: 0.75,
+            max_distance: f32::INFINITY,
+        }
+    }
+
+    pub fn with_target_weight(mut self, target_weight: f32) -> Self {
+        self.target_weight = target_weight;
+        self
+    }
+
+    pub fn with_max_distance(mut self, max_distance: f32) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// The framed point between `target` and `aim`.
+    pub fn frame(&self, target: Vec2, aim: Vec2) -> Vec2 {
+        let offset = (aim - target) * (1. - self.target_weight.clamp(0., 1.));
+        target + offset.clamp_length_max(self.max_distance.max(0.))
+    }
+}
+
+/// Follows the average of a [`Binded`] target's positions over the window, rather than its
+/// current position.
+///
+/// Reads much calmer than direct or spring following for targets with noisy motion, such as
+/// ragdolls and knockback, at the cost of lagging behind by roughly half the window.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+#[require(BindSamples)]
+pub struct BindSmoothingWindow(pub Duration);
+
+/// The positions averaged by a [`BindSmoothingWindow`], oldest first.
+#[derive(Debug, Default, Clone, Component)]
+struct BindSamples {
+    target: Option<Entity>,
+    samples: VecDeque<(Duration, Vec3)>,
+}
+
+impl BindSamples {
+    fn average(
+        &mut self,
+        target: Entity,
+        translation: Vec3,
+        now: Duration,
+        window: Duration,
+    ) -> Vec3 {
+        if self.target != Some(target) {
+            self.samples.clear();
+            self.target = Some(target);
+        }
+
+        self.samples.push_back((now, translation));
+        while self
+            .samples
+            .front()
+            .is_some_and(|(time, _)| now.saturating_sub(*time) > window)
+        {
+            self.samples.pop_front();
+        }
+
+        self.samples.iter().map(|(_, sample)| *sample).sum::<Vec3>() / self.samples.len() as f32
+    }
+}
+
+/// Binds the [`MainCamera`] to the first entity with a matching [`Name`].
+///
+/// Resolved into a [`Binded`] once the named entity exists, so scene files can describe camera
+/// targets without knowing entity ids ahead of time.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct BindedByName(pub String);
+
+impl BindedByName {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+}
+
+/// Follows an entity with a damped spring, an alternative to [`Binded`].
+///
+/// `damping` controls overshoot: [`SpringFollow::critical`] converges as fast as possible without
+/// overshooting, lower damping overshoots and settles, higher damping lags behind.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component)]
+#[component(on_insert = on_insert_spring_follow)]
+pub struct SpringFollow {
+    #[entities]
+    pub target: Entity,
+    pub stiffness: f32,
+    pub damping: f32,
+    velocity: Vec2,
+}
+
+impl SpringFollow {
+    pub fn new(target: Entity, stiffness: f32, damping: f32) -> Self {
+        Self {
+            target,
+            stiffness,
+            damping,
+            velocity: Vec2::ZERO,
+        }
+    }
+
+    /// A critically damped spring, which never overshoots the target.
+    pub fn critical(target: Entity, stiffness: f32) -> Self {
+        Self::new(target, stiffness, 2. * stiffness.max(0.).sqrt())
+    }
+
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+}
+
+fn on_insert_spring_follow(mut world: DeferredWorld, context: HookContext) {
+    world.commands().entity(context.entity).remove::<Binded>();
+}
+
+fn camera_spring_follow(
+    camera: Option<
+        Single<(&mut Transform, &mut SpringFollow), (With<MainCamera>, Without<MoveTo>)>,
+    >,
+    targets: Query<(&Transform, Option<&ResolvedCameraOffset>), Without<MainCamera>>,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut spring)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
 
-#[derive(Debug, Default, Clone, Copy, Component)]
+    let Ok((target, offset)) = targets.get(spring.target) else {
+        warn_once!("Camera spring follows entity with no transform");
+        return;
+    };
+
+    let target = target.translation.xy() + offset.map(|o| o.0).unwrap_or_default();
+    let (stiffness, damping) = (spring.stiffness, spring.damping);
+    let position = crate::smoothing::spring(
+        transform.translation.xy(),
+        target,
+        &mut spring.velocity,
+        stiffness,
+        damping,
+        time.delta_secs(),
+    );
+    transform.translation = position.extend(transform.translation.z);
+}
+
+/// Offsets the [`MainCamera`] from this entity while following it, measured according to the
+/// entity's [`CameraOffsetMode`].
+#[derive(Debug, Default, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
+#[require(ResolvedCameraOffset)]
 pub struct CameraOffset(pub Vec2);
 
+/// How the [`CameraOffset`] of the same entity is measured, [`CameraOffsetMode::World`] if absent.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, Default)]
+pub enum CameraOffsetMode {
+    /// World units.
+    #[default]
+    World,
+    /// Fractions of the visible region, converted every frame so that the composition holds
+    /// across zoom levels and canvas resolutions.
+    ///
+    /// `CameraOffset(Vec2::new(0., 1. / 6.))` keeps the target a third of the way up from the
+    /// bottom of the screen.
+    ScreenFraction,
+}
+
+/// The [`CameraOffset`] in world units, as of the start of the follow logic.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+pub(crate) struct ResolvedCameraOffset(pub Vec2);
+
+fn resolve_camera_offsets(
+    mut offsets: Query<(
+        &CameraOffset,
+        Option<&CameraOffsetMode>,
+        &mut ResolvedCameraOffset,
+    )>,
+    visible: VisibleWorldRect,
+) {
+    let size = visible.size();
+    for (offset, mode, mut resolved) in offsets.iter_mut() {
+        let world = match mode.copied().unwrap_or_default() {
+            CameraOffsetMode::World => offset.0,
+            CameraOffsetMode::ScreenFraction => size.map_or(Vec2::ZERO, |size| offset.0 * size),
+        };
+        resolved.set_if_neq(ResolvedCameraOffset(world));
+    }
+}
+
+fn resolve_binded_by_name(
+    mut commands: Commands,
+    unresolved: Query<(Entity, &BindedByName)>,
+    names: Query<(Entity, &Name)>,
+) {
+    for (entity, binded) in unresolved.iter() {
+        if let Some((target, _)) = names.iter().find(|(_, name)| name.as_str() == binded.0) {
+            commands
+                .entity(entity)
+                .remove::<BindedByName>()
+                .insert(Binded(target));
+        }
+    }
+}
+
 pub fn bind_camera<M: Component>(
     entity: Option<Single<Entity, (With<M>, With<Transform>)>>,
     camera: Option<Single<Entity, With<MainCamera>>>,
@@ -283,64 +958,219 @@ pub fn bind_camera<M: Component>(
     }
 }
 
-fn camera_move_to(
-    camera: Option<Single<(Entity, &mut Transform, &mut MoveTo), With<MainCamera>>>,
-    targets: Query<(&Transform, Option<&CameraOffset>), Without<MainCamera>>,
+fn move_to(
+    mut moves: Query<(
+        Entity,
+        &mut MoveTo,
+        Option<&mut Projection>,
+        Option<&mut CameraConstraints>,
+        Has<MainCamera>,
+    )>,
+    mut transforms: Query<(&mut Transform, Option<&ResolvedCameraOffset>)>,
     mut commands: Commands,
     time: Res<Time>,
 ) {
-    if let Some((entity, mut transform, mut move_to)) = camera.map(|c| c.into_inner()) {
+    for (entity, mut move_to, projection, constraints, is_camera) in moves.iter_mut() {
         move_to.tick(time.delta());
 
+        if let (Some((start, end)), Some(mut projection)) = (move_to.zoom, projection) {
+            if let Projection::Orthographic(ortho) = projection.as_mut() {
+                ortho.scale = start.lerp(end, move_to.easing.sample(move_to.timer.fraction()));
+            }
+        }
+
+        let t = move_to.easing.sample(move_to.timer.fraction());
+        let translation = match move_to.domain {
+            Domain::Positions { start, end } => Some(start.lerp(move_to.clamp_end(end), t)),
+            Domain::Entity { start, end } => {
+                transforms.get(end).ok().map(|(target, offset)| {
+                    // only the camera is offset from its targets
+                    let offset = offset
+                        .filter(|_| is_camera)
+                        .map(|o| o.0)
+                        .unwrap_or_default();
+                    start.lerp(move_to.clamp_end(target.translation + offset.extend(0.)), t)
+                })
+            }
+        };
+
+        // Land exactly on the end, including when the move is skipped with `set_fraction`.
+        if let (Some(translation), Ok((mut transform, _))) =
+            (translation, transforms.get_mut(entity))
+        {
+            transform.translation = translation;
+        }
+
         if move_to.complete() {
+            // land on the end rather than easing into it through the constraints
+            if let Some(mut constraints) = constraints {
+                constraints.reset();
+            }
+
             let mut entity = commands.entity(entity);
             entity.remove::<MoveTo>();
 
-            if let Some(target) = move_to.domain.target() {
+            if let Some(target) = move_to.domain.target().filter(|_| is_camera) {
                 entity.insert(Binded(target));
             }
-        } else {
-            let translation = match move_to.domain {
-                Domain::Positions { start, end } => {
-                    let curve = EasingCurve::new(start, end, move_to.easing);
-                    curve.sample(move_to.timer.fraction())
-                }
-                Domain::Entity { start, end } => {
-                    let Ok((target, offset)) = targets.get(end) else {
-                        return;
-                    };
-
-                    let curve = EasingCurve::new(
-                        start,
-                        target.translation + offset.map(|o| o.0).unwrap_or_default().extend(0.),
-                        move_to.easing,
-                    );
-                    curve.sample(move_to.timer.fraction())
-                }
-            };
-
-            if let Some(t) = translation {
-                transform.translation = t;
-            }
         }
     }
 }
 
 fn camera_binded(
-    camera: Option<Single<(&mut Transform, &Binded), With<MainCamera>>>,
-    transforms: Query<(&Transform, Option<&CameraOffset>), Without<MainCamera>>,
+    mut commands: Commands,
+    camera: Option<
+        Single<
+            (
+                Entity,
+                &mut Transform,
+                &Binded,
+                Option<&mut SnapThreshold>,
+                Option<&mut CameraKinematics>,
+                Option<&BindedMode>,
+                Option<(&BindSmoothingWindow, &mut BindSamples)>,
+                Option<&AimFraming>,
+                Option<&mut CameraConstraints>,
+            ),
+            With<MainCamera>,
+        >,
+    >,
+    transforms: Query<(&Transform, Option<&ResolvedCameraOffset>), Without<MainCamera>>,
+    time: Res<Time>,
 ) {
-    if let Some((mut transform, binded)) = camera.map(|c| c.into_inner()) {
+    if let Some((
+        entity,
+        mut transform,
+        binded,
+        snap,
+        kinematics,
+        mode,
+        smoothing,
+        aim,
+        constraints,
+    )) = camera.map(|c| c.into_inner())
+    {
         if let Ok((t, offset)) = transforms.get(binded.0) {
-            transform.translation =
-                t.translation + offset.map(|o| o.0).unwrap_or_default().extend(0.);
+            if let Some(mut snap) = snap {
+                let teleported = snap.last_target.is_some_and(|(last_entity, last)| {
+                    last_entity == binded.0
+                        && last.xy().distance_squared(t.translation.xy())
+                            > snap.threshold * snap.threshold
+                });
+                snap.last_target = Some((binded.0, t.translation));
+
+                if teleported {
+                    match snap.response {
+                        TeleportResponse::Snap => {
+                            if let Some(mut kinematics) = kinematics {
+                                kinematics.reset();
+                            }
+                            if let Some((_, samples)) = smoothing.as_mut() {
+                                samples.samples.clear();
+                            }
+                            if let Some(mut constraints) = constraints {
+                                constraints.reset();
+                            }
+                        }
+                        TeleportResponse::MoveTo { duration, easing } => {
+                            // the target may keep moving during the blend, which is not a teleport
+                            snap.last_target = None;
+                            commands
+                                .entity(entity)
+                                .insert(MoveTo::from_current_to_entity(binded.0, duration, easing));
+                            return;
+                        }
+                    }
+                }
+            }
+
+            let mut target = match smoothing {
+                Some((window, mut samples)) => {
+                    samples.average(binded.0, t.translation, time.elapsed(), window.0)
+                }
+                None => t.translation,
+            };
+
+            if let Some(aim) = aim {
+                match transforms.get(aim.aim) {
+                    Ok((aim_t, _)) => {
+                        target = aim
+                            .frame(target.xy(), aim_t.translation.xy())
+                            .extend(target.z);
+                    }
+                    Err(_) => warn_once!("Camera aims at entity with no transform"),
+                }
+            }
+
+            let mode = mode.copied().unwrap_or_default();
+            let offset = offset.map(|o| o.0).unwrap_or_default().extend(0.);
+            if mode.rotation {
+                // the offset is attached to the target, e.g. ahead of a turning vehicle
+                transform.translation = target + t.rotation * offset;
+                transform.rotation = t.rotation;
+            } else {
+                transform.translation = target + offset;
+            }
+            if mode.scale {
+                transform.scale = t.scale;
+            }
         } else {
             warn_once!("Camera binded to entity with no transform");
         }
     }
 }
 
-#[derive(Default, Component)]
+/// Determines how a [`Binded`] camera reacts when its target moves farther than `threshold` in a
+/// single frame, e.g. when respawning or fast travelling.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component)]
+pub struct SnapThreshold {
+    pub threshold: f32,
+    pub response: TeleportResponse,
+    #[reflect(ignore)]
+    last_target: Option<(Entity, Vec3)>,
+}
+
+impl SnapThreshold {
+    pub fn new(threshold: f32, response: TeleportResponse) -> Self {
+        Self {
+            threshold,
+            response,
+            last_target: None,
+        }
+    }
+
+    /// Snap to the target when it moves farther than `threshold` in a single frame.
+    pub fn snap(threshold: f32) -> Self {
+        Self::new(threshold, TeleportResponse::Snap)
+    }
+
+    /// Move to the target when it moves farther than `threshold` in a single frame.
+    pub fn move_to(threshold: f32, duration: Duration, easing: impl Into<OptixEase>) -> Self {
+        Self::new(
+            threshold,
+            TeleportResponse::MoveTo {
+                duration,
+                easing: easing.into(),
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Reflect)]
+pub enum TeleportResponse {
+    /// Snaps to the target instantly, bypassing any [`CameraKinematics`].
+    Snap,
+    /// Moves to the target with a [`MoveTo`], then binds again.
+    MoveTo {
+        duration: Duration,
+        #[reflect(ignore)]
+        easing: OptixEase,
+    },
+}
+
+#[derive(Default, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct PixelSnap;
 
 #[derive(Component)]
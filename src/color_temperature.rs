@@ -0,0 +1,149 @@
+use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use bevy::asset::weak_handle;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_resource::ShaderRef;
+use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
+use serde::Deserialize;
+
+pub const COLOR_TEMPERATURE_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("d41c8b27-5e93-4f6a-b1d0-8a7e2c9f3b54");
+
+pub struct ColorTemperaturePlugin;
+
+impl Plugin for ColorTemperaturePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ColorTemperatureSettings>()
+            .add_plugins(PostProcessPlugin::<ColorTemperatureSettings>::default())
+            .add_systems(
+                PostUpdate,
+                drive_color_temperature
+                    .run_if(resource_exists::<TimeOfDay>.and(resource_exists::<DayCycle>)),
+            );
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
+
+        load_internal_asset!(
+            app,
+            COLOR_TEMPERATURE_SHADER_HANDLE,
+            "shaders/color_temperature.wgsl",
+            Shader::from_wgsl
+        );
+    }
+}
+
+/// White balance and exposure for the whole screen.
+#[derive(
+    Debug,
+    Default,
+    Component,
+    Clone,
+    Copy,
+    PartialEq,
+    ExtractComponent,
+    ShaderType,
+    Reflect,
+    Deserialize,
+)]
+#[serde(default)]
+pub struct ColorTemperatureSettings {
+    /// Cool to warm, in the range `[-1, 1]`.
+    pub temperature: f32,
+    /// Green to magenta, in the range `[-1, 1]`.
+    pub tint: f32,
+    /// Exposure in stops.
+    pub exposure: f32,
+    // WebGL2 structs must be 16 byte aligned, wherever `SIXTEEN_BYTE_ALIGNMENT` is defined. The
+    // padding is public so that struct literals may end with `..default()` on every target.
+    #[cfg(all(feature = "webgl2", target_arch = "wasm32", not(feature = "webgpu")))]
+    #[doc(hidden)]
+    #[reflect(ignore)]
+    #[serde(skip)]
+    pub _webgl2_padding: f32,
+}
+
+impl ColorTemperatureSettings {
+    pub fn new(temperature: f32, tint: f32, exposure: f32) -> Self {
+        Self {
+            temperature,
+            tint,
+            exposure,
+            ..Default::default()
+        }
+    }
+
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::new(
+            self.temperature.lerp(other.temperature, t),
+            self.tint.lerp(other.tint, t),
+            self.exposure.lerp(other.exposure, t),
+        )
+    }
+}
+
+impl PostProcessMaterial for ColorTemperatureSettings {
+    fn fragment_shader() -> ShaderRef {
+        COLOR_TEMPERATURE_SHADER_HANDLE.into()
+    }
+}
+
+/// The time of day in the range `[0, 1)`, advanced by the user.
+///
+/// Drives every [`ColorTemperatureSettings`] through the [`DayCycle`].
+#[derive(Debug, Default, Clone, Copy, Resource)]
+pub struct TimeOfDay(pub f32);
+
+/// Keyframes of [`ColorTemperatureSettings`] over the [`TimeOfDay`], wrapping from the last
+/// keyframe back to the first.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct DayCycle {
+    keys: Vec<(f32, ColorTemperatureSettings)>,
+}
+
+impl DayCycle {
+    pub fn new(keys: impl IntoIterator<Item = (f32, ColorTemperatureSettings)>) -> Self {
+        keys.into_iter()
+            .fold(Self::default(), |cycle, (time, settings)| {
+                cycle.with(time, settings)
+            })
+    }
+
+    pub fn with(mut self, time: f32, settings: ColorTemperatureSettings) -> Self {
+        let time = time.rem_euclid(1.);
+        let index = self.keys.partition_point(|(other, _)| *other <= time);
+        self.keys.insert(index, (time, settings));
+        self
+    }
+
+    pub fn sample(&self, time: f32) -> Option<ColorTemperatureSettings> {
+        let time = time.rem_euclid(1.);
+        let next = self.keys.partition_point(|(other, _)| *other <= time);
+        let (start, from) = next
+            .checked_sub(1)
+            .map_or(self.keys.last(), |previous| self.keys.get(previous))?;
+        let (end, to) = self.keys.get(next).or(self.keys.first())?;
+
+        // distances wrap around midnight
+        let span = (end - start).rem_euclid(1.);
+        if span <= f32::EPSILON {
+            return Some(*from);
+        }
+        let t = (time - start).rem_euclid(1.) / span;
+        Some(from.lerp(to, t))
+    }
+}
+
+fn drive_color_temperature(
+    time: Res<TimeOfDay>,
+    cycle: Res<DayCycle>,
+    mut settings: Query<&mut ColorTemperatureSettings>,
+) {
+    let Some(sampled) = cycle.sample(time.0) else {
+        return;
+    };
+
+    for mut settings in settings.iter_mut() {
+        settings.set_if_neq(sampled);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use bevy::asset::weak_handle;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_resource::ShaderRef;
+use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
+
+pub const COLORBLIND_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("3f8d2a61-b4c7-4e9a-8d15-c0a7e6b92f43");
+
+pub struct ColorblindFilterPlugin;
+
+impl Plugin for ColorblindFilterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(PostProcessPlugin::<ColorblindFilterSettings>::default());
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
+
+        load_internal_asset!(
+            app,
+            COLORBLIND_SHADER_HANDLE,
+            "shaders/colorblind.wgsl",
+            Shader::from_wgsl
+        );
+    }
+}
+
+/// The color vision deficiency targeted by a [`ColorblindFilterSettings`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDeficiency {
+    /// Red weakness.
+    #[default]
+    Protanopia,
+    /// Green weakness.
+    Deuteranopia,
+    /// Blue weakness.
+    Tritanopia,
+}
+
+/// How a [`ColorblindFilterSettings`] treats the screen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorblindMode {
+    /// Daltonizes the screen, shifting the colors lost to the deficiency into distinguishable
+    /// ones.
+    #[default]
+    Correct,
+    /// Simulates the deficiency, for testing that the game reads well without assistance.
+    Simulate,
+}
+
+/// Assists players with a color vision deficiency, or simulates one.
+#[derive(Debug, Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct ColorblindFilterSettings {
+    deficiency: u32,
+    mode: u32,
+    /// Blends from the unfiltered screen at `0` to the fully filtered screen at `1`.
+    pub strength: f32,
+    // WebGL2 structs must be 16 byte aligned, wherever `SIXTEEN_BYTE_ALIGNMENT` is defined.
+    #[cfg(all(feature = "webgl2", target_arch = "wasm32", not(feature = "webgpu")))]
+    _webgl2_padding: f32,
+}
+
+impl Default for ColorblindFilterSettings {
+    fn default() -> Self {
+        Self::correct(ColorDeficiency::default())
+    }
+}
+
+impl ColorblindFilterSettings {
+    pub fn new(deficiency: ColorDeficiency, mode: ColorblindMode) -> Self {
+        Self {
+            deficiency: deficiency as u32,
+            mode: mode as u32,
+            strength: 1.,
+            #[cfg(all(feature = "webgl2", target_arch = "wasm32", not(feature = "webgpu")))]
+            _webgl2_padding: 0.,
+        }
+    }
+
+    /// Daltonizes the screen for `deficiency`.
+    pub fn correct(deficiency: ColorDeficiency) -> Self {
+        Self::new(deficiency, ColorblindMode::Correct)
+    }
+
+    /// Simulates how the screen appears with `deficiency`.
+    pub fn simulate(deficiency: ColorDeficiency) -> Self {
+        Self::new(deficiency, ColorblindMode::Simulate)
+    }
+
+    pub fn with_strength(mut self, strength: f32) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    pub fn deficiency(&self) -> ColorDeficiency {
+        match self.deficiency {
+            1 => ColorDeficiency::Deuteranopia,
+            2 => ColorDeficiency::Tritanopia,
+            _ => ColorDeficiency::Protanopia,
+        }
+    }
+
+    pub fn set_deficiency(&mut self, deficiency: ColorDeficiency) {
+        self.deficiency = deficiency as u32;
+    }
+
+    pub fn mode(&self) -> ColorblindMode {
+        match self.mode {
+            1 => ColorblindMode::Simulate,
+            _ => ColorblindMode::Correct,
+        }
+    }
+
+    pub fn set_mode(&mut self, mode: ColorblindMode) {
+        self.mode = mode as u32;
+    }
+}
+
+impl PostProcessMaterial for ColorblindFilterSettings {
+    fn fragment_shader() -> ShaderRef {
+        COLORBLIND_SHADER_HANDLE.into()
+    }
+}
@@ -0,0 +1,270 @@
+//! Namespaced camera and effect commands for developer consoles.
+//!
+//! Enabled with the `console` feature. The [`OptixConsolePlugin`] does not depend on a particular
+//! console crate: forward each submitted line as an [`OptixConsoleCommand`], and print each
+//! [`OptixConsoleOutput`]. With the `bevy_console` feature, the [`OptixBevyConsolePlugin`]
+//! registers every command with `bevy_console` and prints the replies to it.
+//!
+//! Effects are sent as [`OptixEffectEvent`]s, so they require the [`OptixEffectEventPlugin`].
+//! Durations are in seconds and are optional unless noted:
+//!
+//! | Command | Arguments |
+//! | --- | --- |
+//! | `shake` | `trauma` |
+//! | `fx.glitch` | `intensity [duration]` |
+//! | `fx.flash` | `color [duration]` |
+//! | `fx.transition` | `color [duration]` |
+//! | `fx.punch` | `strength [duration]` |
+//! | `fx.hitstop` | `[duration]` |
+//! | `cam.bind` | `entity`, either an id such as `12v1` or a [`Name`] |
+//! | `cam.unbind` | |
+//! | `cam.move` | `x y [duration] [easing]` |
+//! | `cam.zoom` | `scale [duration] [easing]` |
+//! | `cam.cancel` | `[blend]` |
+//!
+//! [`OptixEffectEventPlugin`]: crate::events::OptixEffectEventPlugin
+//! [`OptixBevyConsolePlugin`]: bevy_console_adapter::OptixBevyConsolePlugin
+
+use crate::camera::{Binded, BindedByName, MainCamera};
+use crate::events::OptixEffectEvent;
+use crate::replay::OptixCommand;
+use crate::script::{Args, ScriptError};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Runs every [`OptixConsoleCommand`], replying with an [`OptixConsoleOutput`].
+pub struct OptixConsolePlugin;
+
+impl Plugin for OptixConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OptixConsoleCommand>()
+            .add_event::<OptixConsoleOutput>()
+            .add_event::<OptixEffectEvent>()
+            .add_systems(Update, run_console_commands);
+    }
+}
+
+/// A line submitted to the console.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct OptixConsoleCommand(pub String);
+
+/// A reply to an [`OptixConsoleCommand`], either a confirmation or an error.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct OptixConsoleOutput(pub String);
+
+/// A parsed console command.
+///
+/// ```
+/// # use bevy_optix::console::ConsoleCommand;
+/// let command = "cam.move 10 20 cubic_out".parse::<ConsoleCommand>();
+/// assert!(command.is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsoleCommand {
+    Effect(OptixEffectEvent),
+    Camera(OptixCommand),
+    Bind(Entity),
+    BindByName(String),
+    Unbind,
+}
+
+impl std::str::FromStr for ConsoleCommand {
+    type Err = ScriptError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let name = words.next().ok_or(ScriptError::Empty)?;
+        let mut args = Args::new(name, words);
+        let seconds = Duration::from_secs_f32;
+
+        let command = match name {
+            "shake" => Self::Effect(OptixEffectEvent::Trauma(args.f32("trauma")?)),
+            "fx.glitch" => Self::Effect(OptixEffectEvent::GlitchBurst {
+                intensity: args.f32("intensity")?,
+                duration: args.optional_duration("duration")?.unwrap_or(seconds(0.5)),
+            }),
+            "fx.flash" => Self::Effect(OptixEffectEvent::Flash {
+                color: args.color("color")?,
+                duration: args.optional_duration("duration")?.unwrap_or(seconds(0.2)),
+            }),
+            "fx.transition" => Self::Effect(OptixEffectEvent::Transition {
+                color: args.color("color")?,
+                duration: args.optional_duration("duration")?.unwrap_or(seconds(1.)),
+            }),
+            "fx.punch" => Self::Effect(OptixEffectEvent::ZoomPunch {
+                strength: args.f32("strength")?,
+                duration: args.optional_duration("duration")?.unwrap_or(seconds(0.3)),
+            }),
+            "fx.hitstop" => Self::Effect(OptixEffectEvent::Hitstop(
+                args.optional_duration("duration")?.unwrap_or(seconds(0.1)),
+            )),
+            "cam.bind" => {
+                let target = args.next("entity")?;
+                match parse_entity(target) {
+                    Some(entity) => Self::Bind(entity),
+                    None => Self::BindByName(target.to_string()),
+                }
+            }
+            "cam.unbind" => Self::Unbind,
+            "cam.move" => Self::Camera(OptixCommand::MoveTo {
+                translation: args.vec2("x", "y")?,
+                duration: args.optional_duration("duration")?.unwrap_or_default(),
+                easing: args.easing()?,
+            }),
+            "cam.zoom" => Self::Camera(OptixCommand::ZoomTo {
+                scale: args.f32("scale")?,
+                duration: args.optional_duration("duration")?.unwrap_or_default(),
+                easing: args.easing()?,
+            }),
+            "cam.cancel" => Self::Camera(OptixCommand::CancelMove {
+                blend: args.optional_duration("blend")?.unwrap_or_default(),
+            }),
+            _ => return Err(ScriptError::UnknownCommand(name.to_string())),
+        };
+
+        args.finish()?;
+        Ok(command)
+    }
+}
+
+/// Parses an entity formatted as `index` or `index`v`generation`.
+fn parse_entity(s: &str) -> Option<Entity> {
+    let (index, generation) = s.split_once('v').unwrap_or((s, "1"));
+    let index = index.parse::<u32>().ok()?;
+    let generation = generation.parse::<u32>().ok()?;
+    Entity::try_from_bits((u64::from(generation) << 32) | u64::from(index)).ok()
+}
+
+fn run_console_commands(
+    mut commands: Commands,
+    mut lines: EventReader<OptixConsoleCommand>,
+    mut effects: EventWriter<OptixEffectEvent>,
+    mut output: EventWriter<OptixConsoleOutput>,
+    camera: Query<Entity, With<MainCamera>>,
+    entities: Query<(), With<Transform>>,
+) {
+    for OptixConsoleCommand(line) in lines.read() {
+        let command = match line.parse::<ConsoleCommand>() {
+            Ok(command) => command,
+            Err(err) => {
+                output.write(OptixConsoleOutput(err.to_string()));
+                continue;
+            }
+        };
+
+        let reply = match command {
+            ConsoleCommand::Effect(effect) => {
+                effects.write(effect);
+                Ok(())
+            }
+            ConsoleCommand::Camera(command) => {
+                command.apply(&mut commands);
+                Ok(())
+            }
+            ConsoleCommand::Bind(target) if !entities.contains(target) => {
+                Err(format!("{target} does not exist or has no `Transform`"))
+            }
+            ConsoleCommand::Bind(target) => camera
+                .single()
+                .map(|camera| {
+                    commands
+                        .entity(camera)
+                        .remove::<BindedByName>()
+                        .insert(Binded(target));
+                })
+                .map_err(|err| err.to_string()),
+            ConsoleCommand::BindByName(name) => camera
+                .single()
+                .map(|camera| {
+                    commands.entity(camera).insert(BindedByName(name));
+                })
+                .map_err(|err| err.to_string()),
+            ConsoleCommand::Unbind => camera
+                .single()
+                .map(|camera| {
+                    commands.entity(camera).remove::<(Binded, BindedByName)>();
+                })
+                .map_err(|err| err.to_string()),
+        };
+
+        output.write(OptixConsoleOutput(match reply {
+            Ok(()) => format!("ok: {line}"),
+            Err(err) => err,
+        }));
+    }
+}
+
+#[cfg(feature = "bevy_console")]
+pub mod bevy_console_adapter {
+    //! Registers the console commands with `bevy_console`.
+
+    use super::{OptixConsoleCommand, OptixConsoleOutput, OptixConsolePlugin};
+    use bevy::prelude::*;
+    use bevy_console::{AddConsoleCommand, ConsoleCommand, PrintConsoleLine};
+
+    /// Adds the [`OptixConsolePlugin`], registering each of its commands with `bevy_console`.
+    ///
+    /// Requires `bevy_console`'s `ConsolePlugin`.
+    pub struct OptixBevyConsolePlugin;
+
+    impl Plugin for OptixBevyConsolePlugin {
+        fn build(&self, app: &mut App) {
+            if !app.is_plugin_added::<OptixConsolePlugin>() {
+                app.add_plugins(OptixConsolePlugin);
+            }
+            register_commands(app);
+            app.add_systems(Update, print_output.after(super::run_console_commands));
+        }
+    }
+
+    /// Forwards each command's arguments to the [`OptixConsolePlugin`] unparsed, so that
+    /// `bevy_console` and [`OptixConsoleCommand`] accept exactly the same lines.
+    macro_rules! forward_commands {
+        ($($command:ident => $name:literal, $about:literal;)*) => {
+            $(
+                #[derive(clap::Parser, bevy_console::ConsoleCommand)]
+                #[command(name = $name, about = $about)]
+                struct $command {
+                    #[arg(allow_hyphen_values = true, trailing_var_arg = true)]
+                    args: Vec<String>,
+                }
+            )*
+
+            fn register_commands(app: &mut App) {
+                $(
+                    app.add_console_command::<$command, _>(
+                        |mut command: ConsoleCommand<$command>,
+                         mut optix: EventWriter<OptixConsoleCommand>| {
+                            if let Some(Ok($command { args })) = command.take() {
+                                optix.write(OptixConsoleCommand(format!("{} {}", $name, args.join(" "))));
+                            }
+                        },
+                    );
+                )*
+            }
+        };
+    }
+
+    forward_commands! {
+        Shake => "shake", "Adds trauma to the main camera";
+        Glitch => "fx.glitch", "Plays a glitch burst";
+        Flash => "fx.flash", "Flashes the screen";
+        Transition => "fx.transition", "Fades the screen out and back in";
+        Punch => "fx.punch", "Punches the camera's zoom";
+        Hitstop => "fx.hitstop", "Freezes virtual time";
+        Bind => "cam.bind", "Binds the main camera to an entity id or name";
+        Unbind => "cam.unbind", "Unbinds the main camera";
+        Move => "cam.move", "Moves the main camera";
+        Zoom => "cam.zoom", "Zooms the main camera";
+        Cancel => "cam.cancel", "Cancels the main camera's move";
+    }
+
+    fn print_output(
+        mut output: EventReader<OptixConsoleOutput>,
+        mut lines: EventWriter<PrintConsoleLine>,
+    ) {
+        for OptixConsoleOutput(line) in output.read() {
+            lines.write(PrintConsoleLine::new(line.clone()));
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! An ordered list of constraints on the [`MainCamera`]'s translation.
+//!
+//! Each constraint receives the output of the one before it, so the order of the
+//! [`CameraConstraints`] decides how they interact. Clamping to the bounds before limiting the
+//! speed, for instance, lets the camera ease back into the bounds, while the reverse keeps it
+//! strictly inside them.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_optix::camera::MainCamera;
+//! # use bevy_optix::bounds::CameraBounds;
+//! # use bevy_optix::constraints::{AxisLock, CameraConstraints, Deadzone};
+//! # use bevy_optix::kinematics::CameraKinematics;
+//! # let mut world = World::new();
+//! world.spawn((
+//!     MainCamera,
+//!     CameraConstraints::new()
+//!         .with(Deadzone::new(Vec2::new(32., 16.)))
+//!         .with(AxisLock::y(0.))
+//!         .with(CameraKinematics::new(400., 1600.))
+//!         .with(CameraBounds::new(Rect::new(-500., -200., 500., 200.))),
+//! ));
+//! ```
+//!
+//! Implement [`CameraConstraint`] to add your own.
+//!
+//! The stack is independent of the [`CameraBounds`] and [`CameraKinematics`] components, which
+//! keep running as their own systems. Use either the component or the constraint: a camera with
+//! both is constrained twice, which is warned about once.
+//!
+//! Pixel snapping is not a constraint. The [`PixelSnap`] that every [`MainCamera`] requires
+//! always rounds the final translation, after the stack, and restores the sub-pixel translation
+//! on the next frame.
+//!
+//! [`PixelSnap`]: crate::camera::PixelSnap
+
+use crate::bounds::CameraBounds;
+use crate::camera::MainCamera;
+use crate::kinematics::CameraKinematics;
+use crate::visible::{VisibleWorldRect, camera_region};
+use bevy::prelude::*;
+use std::any::TypeId;
+
+/// The state of the camera passed to each [`CameraConstraint`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstraintContext {
+    /// The constrained translation on the previous frame, or `None` on the first frame.
+    pub previous: Option<Vec3>,
+    /// The size of the [`VisibleWorldRect`], if known.
+    pub view: Option<Vec2>,
+    /// Seconds since the previous frame.
+    pub delta: f32,
+}
+
+/// Restricts where the [`MainCamera`] may be.
+pub trait CameraConstraint: Send + Sync + 'static {
+    /// Returns the constrained `translation`.
+    fn constrain(&mut self, translation: Vec3, context: &ConstraintContext) -> Vec3;
+
+    /// Forgets any state accumulated across frames, e.g. after the camera teleports.
+    fn reset(&mut self) {}
+}
+
+/// The constraints applied to the [`MainCamera`], in order.
+#[derive(Default, Component)]
+pub struct CameraConstraints {
+    constraints: Vec<(TypeId, Box<dyn CameraConstraint>)>,
+    previous: Option<Vec3>,
+}
+
+impl CameraConstraints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `constraint`, applying it after the existing constraints.
+    pub fn with(mut self, constraint: impl CameraConstraint) -> Self {
+        self.push(constraint);
+        self
+    }
+
+    /// Appends `constraint`, applying it after the existing constraints.
+    pub fn push<C: CameraConstraint>(&mut self, constraint: C) {
+        self.constraints
+            .push((TypeId::of::<C>(), Box::new(constraint)));
+    }
+
+    /// Inserts `constraint` at `index`, applying it before the constraints that follow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert<C: CameraConstraint>(&mut self, index: usize, constraint: C) {
+        self.constraints
+            .insert(index, (TypeId::of::<C>(), Box::new(constraint)));
+    }
+
+    /// Whether any constraint is a `C`.
+    pub fn contains<C: CameraConstraint>(&self) -> bool {
+        self.constraints
+            .iter()
+            .any(|(kind, _)| *kind == TypeId::of::<C>())
+    }
+
+    /// Removes every constraint.
+    pub fn clear(&mut self) {
+        self.constraints.clear();
+        self.previous = None;
+    }
+
+    pub fn len(&self) -> usize {
+        self.constraints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.constraints.is_empty()
+    }
+
+    /// Resets every constraint, allowing the camera to jump on the next frame.
+    pub fn reset(&mut self) {
+        self.previous = None;
+        for (_, constraint) in self.constraints.iter_mut() {
+            constraint.reset();
+        }
+    }
+
+    /// Applies every constraint to `translation` in order.
+    pub fn constrain(&mut self, translation: Vec3, view: Option<Vec2>, delta: f32) -> Vec3 {
+        let context = ConstraintContext {
+            previous: self.previous,
+            view,
+            delta,
+        };
+        let constrained = self
+            .constraints
+            .iter_mut()
+            .fold(translation, |translation, (_, constraint)| {
+                constraint.constrain(translation, &context)
+            });
+        self.previous = Some(constrained);
+        constrained
+    }
+}
+
+/// Holds the camera still until the target leaves a `size` region around it, then drags the
+/// camera along by the overshoot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Deadzone {
+    pub size: Vec2,
+}
+
+impl Deadzone {
+    pub fn new(size: Vec2) -> Self {
+        Self { size }
+    }
+}
+
+impl CameraConstraint for Deadzone {
+    fn constrain(&mut self, translation: Vec3, context: &ConstraintContext) -> Vec3 {
+        let Some(previous) = context.previous else {
+            return translation;
+        };
+
+        let half = self.size / 2.;
+        let delta = translation.xy() - previous.xy();
+        let overshoot = delta - delta.clamp(-half, half);
+        (previous.xy() + overshoot).extend(translation.z)
+    }
+}
+
+/// Fixes the camera's translation along the locked axes.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AxisLock {
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+}
+
+impl AxisLock {
+    /// Locks the horizontal axis at `x`.
+    pub fn x(x: f32) -> Self {
+        Self {
+            x: Some(x),
+            y: None,
+        }
+    }
+
+    /// Locks the vertical axis at `y`.
+    pub fn y(y: f32) -> Self {
+        Self {
+            x: None,
+            y: Some(y),
+        }
+    }
+}
+
+impl CameraConstraint for AxisLock {
+    fn constrain(&mut self, translation: Vec3, _: &ConstraintContext) -> Vec3 {
+        Vec3::new(
+            self.x.unwrap_or(translation.x),
+            self.y.unwrap_or(translation.y),
+            translation.z,
+        )
+    }
+}
+
+impl CameraConstraint for CameraBounds {
+    fn constrain(&mut self, translation: Vec3, context: &ConstraintContext) -> Vec3 {
+        let Some(view) = context.view else {
+            return translation;
+        };
+
+        let region = camera_region(self.rect, view);
+        let clamped = translation.xy().clamp(region.min, region.max);
+        clamped.extend(translation.z)
+    }
+}
+
+impl CameraConstraint for CameraKinematics {
+    fn constrain(&mut self, translation: Vec3, context: &ConstraintContext) -> Vec3 {
+        self.limit(translation.xy(), context.delta)
+            .extend(translation.z)
+    }
+
+    fn reset(&mut self) {
+        CameraKinematics::reset(self);
+    }
+}
+
+pub(crate) fn apply_camera_constraints(
+    camera: Option<
+        Single<
+            (
+                &mut Transform,
+                &mut CameraConstraints,
+                Has<CameraBounds>,
+                Has<CameraKinematics>,
+            ),
+            With<MainCamera>,
+        >,
+    >,
+    visible: VisibleWorldRect,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut constraints, bounds, kinematics)) = camera.map(|c| c.into_inner())
+    else {
+        return;
+    };
+
+    if (bounds && constraints.contains::<CameraBounds>())
+        || (kinematics && constraints.contains::<CameraKinematics>())
+    {
+        warn_once!("Camera has a constraint both as a component and in its `CameraConstraints`");
+    }
+
+    let view = visible.size();
+    let constrained = constraints.constrain(transform.translation, view, time.delta_secs());
+    if constrained != transform.translation {
+        transform.translation = constrained;
+    }
+}
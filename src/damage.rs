@@ -0,0 +1,187 @@
+use crate::accessibility::PhotosensitivitySettings;
+use crate::effect_target::{EffectTarget, EffectTargetCommands};
+use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use crate::smoothing::decay;
+use bevy::asset::weak_handle;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_resource::ShaderRef;
+use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
+
+pub const DAMAGE_VIGNETTE_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("a2d7e5c3-61f8-4b0e-9c4d-7e3b8f12d6a9");
+
+/// Darkens the edges of the screen with a [`DamageVignette`] driven by the [`DamageSignal`].
+#[derive(Default)]
+pub struct DamageVignettePlugin {
+    /// Inserts a default [`DamageVignette`] on the targeted camera at startup.
+    ///
+    /// [`EffectTarget::Canvas`] leaves the high resolution layer unaffected.
+    pub target: Option<EffectTarget>,
+}
+
+impl DamageVignettePlugin {
+    pub fn with_target(mut self, target: EffectTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+
+impl Plugin for DamageVignettePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DamageSignal>()
+            .add_plugins(PostProcessPlugin::<DamageVignette>::default())
+            .add_systems(PostUpdate, (update_damage_signal, damage_vignette).chain());
+
+        if let Some(target) = self.target {
+            app.add_systems(PostStartup, move |mut commands: Commands| {
+                commands.insert_effect(target, DamageVignette::default());
+            });
+        }
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
+
+        load_internal_asset!(
+            app,
+            DAMAGE_VIGNETTE_SHADER_HANDLE,
+            "shaders/damage_vignette.wgsl",
+            Shader::from_wgsl
+        );
+    }
+}
+
+/// A vignette whose `intensity` follows the [`DamageSignal`].
+#[derive(Debug, Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct DamageVignette {
+    /// The color of the vignette, its alpha scaling the vignette's strength.
+    pub color: Vec4,
+    pub intensity: f32,
+    /// The distance from the center of the screen, in screen heights, at which the vignette
+    /// begins when the intensity is 0.
+    pub radius: f32,
+    /// The width of the vignette's gradient.
+    pub softness: f32,
+}
+
+impl Default for DamageVignette {
+    fn default() -> Self {
+        Self::new(Color::srgb(0.6, 0., 0.))
+    }
+}
+
+impl DamageVignette {
+    pub fn new(color: impl Into<Color>) -> Self {
+        Self {
+            color: color.into().to_linear().to_vec4(),
+            intensity: 0.,
+            radius: 0.9,
+            softness: 0.4,
+        }
+    }
+}
+
+impl PostProcessMaterial for DamageVignette {
+    fn fragment_shader() -> ShaderRef {
+        DAMAGE_VIGNETTE_SHADER_HANDLE.into()
+    }
+}
+
+/// Drives the intensity of every [`DamageVignette`], in the range `[0, 1]`.
+///
+/// The signal is the larger of the persistent `level`, e.g. the fraction of health lost, and the
+/// transient pulses added with [`DamageCommands::pulse_damage`].
+///
+/// Respects the [`PhotosensitivitySettings`].
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct DamageSignal {
+    pub level: f32,
+    /// The rate at which the signal rises.
+    pub attack: f32,
+    /// The rate at which the signal, and its pulses, fall.
+    pub decay: f32,
+    pulse: f32,
+    pending: f32,
+    since_pulse: f32,
+    value: f32,
+}
+
+impl Default for DamageSignal {
+    fn default() -> Self {
+        Self {
+            level: 0.,
+            attack: 30.,
+            decay: 3.,
+            pulse: 0.,
+            pending: 0.,
+            since_pulse: f32::INFINITY,
+            value: 0.,
+        }
+    }
+}
+
+impl DamageSignal {
+    pub fn pulse(&mut self, amount: f32) {
+        self.pending += amount;
+    }
+
+    /// The smoothed signal.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+pub trait DamageCommands {
+    /// Adds a transient pulse to the [`DamageSignal`].
+    fn pulse_damage(&mut self, amount: f32);
+}
+
+impl DamageCommands for Commands<'_, '_> {
+    fn pulse_damage(&mut self, amount: f32) {
+        self.queue(move |world: &mut World| {
+            world.get_resource_or_init::<DamageSignal>().pulse(amount);
+        });
+    }
+}
+
+fn update_damage_signal(
+    mut signal: ResMut<DamageSignal>,
+    photosensitivity: Option<Res<PhotosensitivitySettings>>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    let photosensitivity = photosensitivity.as_deref();
+
+    signal.since_pulse += dt;
+    let interval = photosensitivity.map_or(0., |p| p.min_flash_interval());
+    // pulses within the interval are merged into the next flash
+    if signal.pending > 0. && signal.since_pulse >= interval {
+        signal.pulse = (signal.pulse + signal.pending).clamp(0., 1.);
+        signal.pending = 0.;
+        signal.since_pulse = 0.;
+    }
+
+    let mut target = signal.level.max(signal.pulse).clamp(0., 1.);
+    let mut attack = signal.attack;
+    if let Some(photosensitivity) = photosensitivity {
+        target = photosensitivity.cap(target);
+        attack = photosensitivity.attack(attack, signal.decay);
+    }
+
+    let rate = if target > signal.value {
+        attack
+    } else {
+        signal.decay
+    };
+
+    signal.value = decay(signal.value, target, rate, dt);
+    signal.pulse = decay(signal.pulse, 0., signal.decay, dt);
+}
+
+fn damage_vignette(signal: Res<DamageSignal>, mut vignettes: Query<&mut DamageVignette>) {
+    for mut vignette in vignettes.iter_mut() {
+        if vignette.intensity != signal.value {
+            vignette.intensity = signal.value;
+        }
+    }
+}
@@ -5,6 +5,7 @@ use bevy::ecs::system::RunSystemOnce;
 use bevy::ecs::world::DeferredWorld;
 use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
 
 use crate::pixel_perfect::HIGH_RES_LAYER;
 
@@ -17,6 +18,36 @@ impl Plugin for DebugPlugin {
     }
 }
 
+/// The layer that a debug primitive renders on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DebugLayer {
+    /// The low resolution canvas, aligned with the world content it annotates.
+    #[default]
+    Canvas,
+    /// The [`HIGH_RES_LAYER`], which is crisp at any window size.
+    HighRes,
+}
+
+impl DebugLayer {
+    pub fn render_layers(self) -> RenderLayers {
+        match self {
+            Self::Canvas => RenderLayers::default(),
+            Self::HighRes => HIGH_RES_LAYER,
+        }
+    }
+
+    /// Inserts the [`RenderLayers`] of a non-default layer, keeping any the entity already has.
+    fn insert_render_layers(self, entity: &mut EntityCommands) {
+        if self != Self::default() {
+            entity.insert_if_new(self.render_layers());
+        }
+    }
+}
+
+/// Marks text spawned by the debug systems.
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct DebugText;
+
 pub trait DebugComponentAppExt {
     fn debug_component<T: Component + core::fmt::Debug>(&mut self) -> &mut Self;
 }
@@ -35,13 +66,25 @@ pub fn debug_res<R: Resource + core::fmt::Debug>(
         if res.is_changed() {
             let entity = text.get_or_insert_with(|| {
                 commands
-                    .spawn((Text2d::default(), HIGH_RES_LAYER, transform, anchor))
+                    .spawn((
+                        Text2d::default(),
+                        DebugText,
+                        HIGH_RES_LAYER,
+                        transform,
+                        anchor,
+                    ))
                     .id()
             });
 
             let mut entity = match commands.get_entity(*entity) {
                 Ok(entity) => entity,
-                Err(_) => commands.spawn((Text2d::default(), HIGH_RES_LAYER, transform, anchor)),
+                Err(_) => commands.spawn((
+                    Text2d::default(),
+                    DebugText,
+                    HIGH_RES_LAYER,
+                    transform,
+                    anchor,
+                )),
             };
 
             entity.insert(Text2d::new(format!("{:?}", res.as_ref())));
@@ -57,13 +100,25 @@ pub fn debug_single<C: Component + core::fmt::Debug>(
         if single.is_changed() {
             let entity = text.get_or_insert_with(|| {
                 commands
-                    .spawn((Text2d::default(), HIGH_RES_LAYER, transform, anchor))
+                    .spawn((
+                        Text2d::default(),
+                        DebugText,
+                        HIGH_RES_LAYER,
+                        transform,
+                        anchor,
+                    ))
                     .id()
             });
 
             let mut entity = match commands.get_entity(*entity) {
                 Ok(entity) => entity,
-                Err(_) => commands.spawn((Text2d::default(), HIGH_RES_LAYER, transform, anchor)),
+                Err(_) => commands.spawn((
+                    Text2d::default(),
+                    DebugText,
+                    HIGH_RES_LAYER,
+                    transform,
+                    anchor,
+                )),
             };
 
             entity.insert(Text2d::new(format!("{:?}", single.into_inner().as_ref())));
@@ -72,11 +127,25 @@ pub fn debug_single<C: Component + core::fmt::Debug>(
 }
 
 #[derive(Component)]
-pub struct DebugComponent<T>(fn(&mut EntityCommands), PhantomData<fn(T)>);
+pub struct DebugComponent<T> {
+    bundle: fn(&mut EntityCommands),
+    layer: Option<DebugLayer>,
+    _marker: PhantomData<fn(T)>,
+}
 
 impl<T> DebugComponent<T> {
     pub fn new(bundle: fn(&mut EntityCommands)) -> Self {
-        Self(bundle, PhantomData)
+        Self {
+            bundle,
+            layer: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Renders the text on `layer`, otherwise on the default [`RenderLayers`].
+    pub fn with_layer(mut self, layer: DebugLayer) -> Self {
+        self.layer = Some(layer);
+        self
     }
 }
 
@@ -89,8 +158,11 @@ fn init_debug_component<T: Component>(
 ) {
     for (entity, debug) in debug.iter() {
         commands.entity(entity).insert(Debugged);
-        let mut child = commands.spawn(Text2d::default());
-        (debug.0)(&mut child);
+        let mut child = commands.spawn((Text2d::default(), DebugText));
+        if let Some(layer) = debug.layer {
+            child.insert(layer.render_layers());
+        }
+        (debug.bundle)(&mut child);
         child.insert(ChildOf(entity));
     }
 }
@@ -115,6 +187,7 @@ fn debug_component<T: Component + core::fmt::Debug>(
 pub struct DebugRect {
     pub rect: Rect,
     pub color: Color,
+    pub layer: DebugLayer,
 }
 
 impl DebugRect {
@@ -122,9 +195,15 @@ impl DebugRect {
         Self {
             rect,
             color: color.into(),
+            layer: DebugLayer::Canvas,
         }
     }
 
+    pub fn with_layer(mut self, layer: DebugLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
     pub fn from_size(size: Vec2) -> Self {
         Self::new(Rect::from_center_size(Vec2::ZERO, size), Color::WHITE)
     }
@@ -140,6 +219,7 @@ impl DebugRect {
 pub struct DebugCircle {
     pub radius: f32,
     pub color: Color,
+    pub layer: DebugLayer,
 }
 
 impl DebugCircle {
@@ -151,8 +231,14 @@ impl DebugCircle {
         Self {
             radius,
             color: color.into(),
+            layer: DebugLayer::Canvas,
         }
     }
+
+    pub fn with_layer(mut self, layer: DebugLayer) -> Self {
+        self.layer = layer;
+        self
+    }
 }
 
 #[derive(Default, Resource)]
@@ -168,11 +254,13 @@ impl DebugRect {
                 .run_system_once(
                     move |mut commands: Commands, debug_rects: Query<&DebugRect>| {
                         if let Ok(rect) = debug_rects.get(ctx.entity) {
-                            commands.entity(ctx.entity).insert(Sprite {
+                            let mut entity = commands.entity(ctx.entity);
+                            entity.insert(Sprite {
                                 rect: Some(rect.rect),
                                 color: rect.color,
                                 ..Default::default()
                             });
+                            rect.layer.insert_render_layers(&mut entity);
                         }
                     },
                 )
@@ -207,9 +295,9 @@ impl DebugCircle {
                                 })
                                 .clone();
 
-                            commands
-                                .entity(ctx.entity)
-                                .insert((Mesh2d(mesh), MeshMaterial2d(material)));
+                            let mut entity = commands.entity(ctx.entity);
+                            entity.insert((Mesh2d(mesh), MeshMaterial2d(material)));
+                            circle.layer.insert_render_layers(&mut entity);
                         }
                     },
                 )
@@ -0,0 +1,149 @@
+//! Easing curves accepted by every optix transition.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_optix::ease::OptixEase;
+//! const STEPS: &[Vec2] = &[Vec2::new(0., 0.), Vec2::new(0.5, 0.8), Vec2::new(1., 1.)];
+//!
+//! let ease = OptixEase::Piecewise(STEPS);
+//! assert_eq!(ease.sample(0.25), 0.4);
+//! ```
+
+use bevy::math::curve::{Curve, Interval};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// An [`EaseFunction`], or one of the parameterized curves that it does not provide.
+///
+/// Every variant maps `0` to `0` and `1` to `1`, except [`OptixEase::Piecewise`], which starts and
+/// ends at its first and last points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum OptixEase {
+    Function(EaseFunction),
+    /// The step response of a damped spring, which overshoots and settles on the end.
+    Spring {
+        /// The number of oscillations over the transition.
+        frequency: f32,
+        /// The damping ratio, where `0` never settles and `1` never overshoots.
+        damping: f32,
+    },
+    /// Overshoots the end and oscillates into place.
+    ElasticOut {
+        /// The peak of the first overshoot, at least `1`.
+        amplitude: f32,
+        /// The length of an oscillation as a fraction of the transition.
+        period: f32,
+    },
+    /// A cubic bezier easing, see [`OptixEase::cubic_bezier`].
+    CubicBezier(CubicSegment<Vec2>),
+    /// Linearly interpolates between `(t, value)` points sorted by `t`.
+    ///
+    /// The curve is clamped to the first and last points.
+    ///
+    /// Borrows its points, so it cannot be serialized.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    Piecewise(&'static [Vec2]),
+}
+
+impl Default for OptixEase {
+    fn default() -> Self {
+        Self::Function(EaseFunction::Linear)
+    }
+}
+
+impl From<EaseFunction> for OptixEase {
+    fn from(value: EaseFunction) -> Self {
+        Self::Function(value)
+    }
+}
+
+impl From<CubicSegment<Vec2>> for OptixEase {
+    fn from(value: CubicSegment<Vec2>) -> Self {
+        Self::CubicBezier(value)
+    }
+}
+
+impl OptixEase {
+    pub fn spring(frequency: f32, damping: f32) -> Self {
+        Self::Spring { frequency, damping }
+    }
+
+    pub fn elastic_out(amplitude: f32, period: f32) -> Self {
+        Self::ElasticOut { amplitude, period }
+    }
+
+    /// Equivalent to the CSS `cubic-bezier(p1.x, p1.y, p2.x, p2.y)` easing function.
+    pub fn cubic_bezier(p1: impl Into<Vec2>, p2: impl Into<Vec2>) -> Self {
+        CubicSegment::new_bezier_easing(p1, p2).into()
+    }
+
+    /// Samples the curve at `t`, clamped to `[0, 1]`.
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match *self {
+            Self::Function(function) => function.sample_clamped(t),
+            Self::Spring { frequency, damping } => {
+                // the response does not quite reach 1, so the remainder is blended in
+                let response = |t| spring_response(t, frequency * TAU, damping);
+                response(t) + (1. - response(1.)) * t
+            }
+            Self::ElasticOut { amplitude, period } => {
+                if t == 0. || t == 1. {
+                    return t;
+                }
+                let amplitude = amplitude.max(1.);
+                let period = period.max(f32::EPSILON);
+                let shift = period / TAU * (1. / amplitude).asin();
+                1. + amplitude * 2f32.powf(-10. * t) * ((t - shift) * TAU / period).sin()
+            }
+            Self::CubicBezier(segment) => segment.ease(t),
+            Self::Piecewise(points) => piecewise(points, t),
+        }
+    }
+}
+
+/// The position of a unit spring released from `0` toward `1` at time `t`.
+fn spring_response(t: f32, omega: f32, damping: f32) -> f32 {
+    let damping = damping.max(0.);
+    if damping < 1. {
+        let damped = omega * (1. - damping * damping).sqrt();
+        let decay = (-damping * omega * t).exp();
+        1. - decay * ((damped * t).cos() + damping * omega / damped * (damped * t).sin())
+    } else {
+        // critically damped, overdamped springs are approximated as critical
+        1. - (1. + omega * t) * (-omega * t).exp()
+    }
+}
+
+fn piecewise(points: &[Vec2], t: f32) -> f32 {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return t;
+    };
+    if t <= first.x {
+        return first.y;
+    }
+
+    points
+        .windows(2)
+        .find(|pair| t <= pair[1].x)
+        .map(|pair| {
+            let span = pair[1].x - pair[0].x;
+            if span <= 0. {
+                pair[1].y
+            } else {
+                pair[0].y.lerp(pair[1].y, (t - pair[0].x) / span)
+            }
+        })
+        .unwrap_or(last.y)
+}
+
+impl Curve<f32> for OptixEase {
+    fn domain(&self) -> Interval {
+        Interval::UNIT
+    }
+
+    fn sample_unchecked(&self, t: f32) -> f32 {
+        self.sample(t)
+    }
+}
@@ -0,0 +1,66 @@
+//! Selects which camera an effect rides on.
+//!
+//! Effects are components on a camera, and only affect what that camera renders:
+//!
+//! - The [`MainCamera`] renders the world into the low resolution canvas. A [`Shake`] or post
+//!   process on the main camera leaves the [`HIGH_RES_LAYER`], e.g. UI, untouched.
+//! - The [`OuterCamera`] composites the canvas and the [`HIGH_RES_LAYER`] into the window, so its
+//!   effects apply to everything on screen.
+//!
+//! [`Shake`]: crate::shake::Shake
+//! [`HIGH_RES_LAYER`]: crate::pixel_perfect::HIGH_RES_LAYER
+
+use crate::camera::MainCamera;
+use crate::pixel_perfect::OuterCamera;
+use bevy::prelude::*;
+
+/// The camera that an effect is applied to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EffectTarget {
+    /// The [`MainCamera`], affecting only the canvas.
+    #[default]
+    Canvas,
+    /// The [`OuterCamera`], affecting the whole window including the high resolution layer.
+    Window,
+}
+
+impl EffectTarget {
+    /// The targeted camera.
+    pub fn camera(self, world: &mut World) -> Result<Entity> {
+        let camera = match self {
+            Self::Canvas => world
+                .query_filtered::<Entity, With<MainCamera>>()
+                .single(world)?,
+            Self::Window => world
+                .query_filtered::<Entity, With<OuterCamera>>()
+                .single(world)?,
+        };
+        Ok(camera)
+    }
+}
+
+pub trait EffectTargetCommands {
+    /// Inserts `effect` on the camera selected by `target`.
+    fn insert_effect(&mut self, target: EffectTarget, effect: impl Bundle);
+
+    /// Removes `B` from the camera selected by `target`.
+    fn remove_effect<B: Bundle>(&mut self, target: EffectTarget);
+}
+
+impl EffectTargetCommands for Commands<'_, '_> {
+    fn insert_effect(&mut self, target: EffectTarget, effect: impl Bundle) {
+        self.queue(move |world: &mut World| -> Result {
+            let camera = target.camera(world)?;
+            world.entity_mut(camera).insert(effect);
+            Ok(())
+        });
+    }
+
+    fn remove_effect<B: Bundle>(&mut self, target: EffectTarget) {
+        self.queue(move |world: &mut World| -> Result {
+            let camera = target.camera(world)?;
+            world.entity_mut(camera).remove::<B>();
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,82 @@
+//! Triggers presentation effects with events.
+//!
+//! Gameplay code can send an [`OptixEffectEvent`] without depending on the individual
+//! [`Commands`] extensions:
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_optix::events::OptixEffectEvent;
+//! # use std::time::Duration;
+//! fn on_hit(mut effects: EventWriter<OptixEffectEvent>) {
+//!     effects.write(OptixEffectEvent::Trauma(0.4));
+//!     effects.write(OptixEffectEvent::Hitstop(Duration::from_millis(60)));
+//! }
+//! ```
+
+use crate::camera::CameraSystem;
+use crate::flash::FlashCommands;
+use crate::glitch::GlitchCommands;
+use crate::hitstop::HitstopCommands;
+use crate::shake::{ShakeSystem, TraumaCommands};
+use crate::zoom::ZoomCommands;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Dispatches every [`OptixEffectEvent`] to its [`Commands`] extension.
+///
+/// Events sent before [`PostUpdate`] are applied in the same frame.
+pub struct OptixEffectEventPlugin;
+
+impl Plugin for OptixEffectEventPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OptixEffectEvent>().add_systems(
+            PostUpdate,
+            dispatch_effect_events
+                .before(CameraSystem::UpdateCamera)
+                .before(ShakeSystem::ApplyTrauma),
+        );
+    }
+}
+
+/// A presentation effect, applied by the [`OptixEffectEventPlugin`].
+///
+/// Each effect requires its own plugin, e.g. [`GlitchBurst`](Self::GlitchBurst) does nothing
+/// without the [`GlitchPlugin`](crate::glitch::GlitchPlugin).
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub enum OptixEffectEvent {
+    /// See [`TraumaCommands::add_trauma`].
+    Trauma(f32),
+    /// See [`GlitchCommands::glitch_burst`].
+    GlitchBurst { intensity: f32, duration: Duration },
+    /// See [`FlashCommands::flash_screen`].
+    Flash { color: Color, duration: Duration },
+    /// See [`ZoomCommands::zoom_punch`].
+    ZoomPunch { strength: f32, duration: Duration },
+    /// See [`HitstopCommands::hitstop`].
+    Hitstop(Duration),
+    /// See [`FlashCommands::screen_transition`].
+    Transition { color: Color, duration: Duration },
+}
+
+impl OptixEffectEvent {
+    /// Issues the effect through its [`Commands`] extension.
+    pub fn apply(self, commands: &mut Commands) {
+        match self {
+            Self::Trauma(trauma) => commands.add_trauma(trauma),
+            Self::GlitchBurst {
+                intensity,
+                duration,
+            } => commands.glitch_burst(intensity, duration),
+            Self::Flash { color, duration } => commands.flash_screen(color, duration),
+            Self::ZoomPunch { strength, duration } => commands.zoom_punch(strength, duration),
+            Self::Hitstop(duration) => commands.hitstop(duration),
+            Self::Transition { color, duration } => commands.screen_transition(color, duration),
+        }
+    }
+}
+
+fn dispatch_effect_events(mut commands: Commands, mut events: EventReader<OptixEffectEvent>) {
+    for event in events.read() {
+        event.apply(&mut commands);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::accessibility::PhotosensitivitySettings;
+use crate::effect_target::{EffectTarget, EffectTargetCommands};
+use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use crate::replay::{OptixCommand, record_command};
+use bevy::asset::weak_handle;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_resource::ShaderRef;
+use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
+use std::time::Duration;
+
+pub const SCREEN_FLASH_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("5c1e9b72-3a48-4d6f-b2e0-8f7a4c19d3e5");
+
+/// Fills the screen with a color through a [`ScreenFlash`], driven by [`ScreenFade`]s.
+#[derive(Default)]
+pub struct ScreenFlashPlugin {
+    /// Inserts a transparent [`ScreenFlash`] on the targeted camera at startup, ready for
+    /// [`FlashCommands`].
+    ///
+    /// [`EffectTarget::Window`] also covers the high resolution layer.
+    pub target: Option<EffectTarget>,
+}
+
+impl ScreenFlashPlugin {
+    pub fn with_target(mut self, target: EffectTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+
+impl Plugin for ScreenFlashPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(PostProcessPlugin::<ScreenFlash>::default())
+            .add_systems(PostUpdate, screen_fade);
+
+        if let Some(target) = self.target {
+            app.add_systems(PostStartup, move |mut commands: Commands| {
+                commands.insert_effect(target, ScreenFlash::default());
+            });
+        }
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
+
+        load_internal_asset!(
+            app,
+            SCREEN_FLASH_SHADER_HANDLE,
+            "shaders/screen_flash.wgsl",
+            Shader::from_wgsl
+        );
+    }
+}
+
+/// Mixes the screen towards `color` by `intensity`.
+#[derive(Debug, Default, Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct ScreenFlash {
+    /// The color of the flash, its alpha scaling the flash's strength.
+    pub color: Vec4,
+    pub intensity: f32,
+}
+
+impl PostProcessMaterial for ScreenFlash {
+    fn fragment_shader() -> ShaderRef {
+        SCREEN_FLASH_SHADER_HANDLE.into()
+    }
+}
+
+/// Drives the [`ScreenFlash`] up to `color` over `attack`, then back down over `release`,
+/// removing itself once finished.
+///
+/// Fades without an attack are flashes, which respect the [`PhotosensitivitySettings`]: their
+/// intensity is capped, gentle fades give them an attack, and flashes within the minimum interval
+/// of the previous one are merged into it.
+#[derive(Debug, Clone, Copy, Component)]
+#[require(ScreenFlash)]
+pub struct ScreenFade {
+    pub color: Color,
+    pub attack: Duration,
+    pub release: Duration,
+    elapsed: Duration,
+    flash: bool,
+}
+
+impl ScreenFade {
+    pub fn new(color: impl Into<Color>, attack: Duration, release: Duration) -> Self {
+        Self {
+            color: color.into(),
+            attack,
+            release,
+            elapsed: Duration::ZERO,
+            flash: attack.is_zero(),
+        }
+    }
+
+    /// Instantly fills the screen, then fades out over `duration`.
+    pub fn flash(color: impl Into<Color>, duration: Duration) -> Self {
+        Self::new(color, Duration::ZERO, duration)
+    }
+
+    /// Fades the screen to `color` and back, fully covered halfway through `duration`.
+    pub fn transition(color: impl Into<Color>, duration: Duration) -> Self {
+        Self::new(color, duration / 2, duration / 2)
+    }
+
+    pub fn finished(&self) -> bool {
+        self.elapsed >= self.attack + self.release
+    }
+
+    /// The intensity of the fade, in the range `[0, 1]`.
+    pub fn intensity(&self) -> f32 {
+        if self.elapsed < self.attack {
+            self.elapsed.as_secs_f32() / self.attack.as_secs_f32()
+        } else if self.release.is_zero() {
+            0.
+        } else {
+            1. - ((self.elapsed - self.attack).as_secs_f32() / self.release.as_secs_f32()).min(1.)
+        }
+    }
+}
+
+pub trait FlashCommands {
+    /// Inserts a [`ScreenFade::flash`] on every entity with a [`ScreenFlash`].
+    fn flash_screen(&mut self, color: impl Into<Color>, duration: Duration);
+
+    /// Inserts a [`ScreenFade::transition`] on every entity with a [`ScreenFlash`].
+    fn screen_transition(&mut self, color: impl Into<Color>, duration: Duration);
+}
+
+impl FlashCommands for Commands<'_, '_> {
+    fn flash_screen(&mut self, color: impl Into<Color>, duration: Duration) {
+        let color = color.into();
+        self.queue(move |world: &mut World| {
+            record_command(world, OptixCommand::Flash { color, duration });
+            insert_fade(world, ScreenFade::flash(color, duration));
+        });
+    }
+
+    fn screen_transition(&mut self, color: impl Into<Color>, duration: Duration) {
+        let color = color.into();
+        self.queue(move |world: &mut World| {
+            record_command(world, OptixCommand::Transition { color, duration });
+            insert_fade(world, ScreenFade::transition(color, duration));
+        });
+    }
+}
+
+fn insert_fade(world: &mut World, mut fade: ScreenFade) {
+    let photosensitivity = world
+        .get_resource::<PhotosensitivitySettings>()
+        .copied()
+        .filter(|_| fade.flash);
+    if let Some(photosensitivity) = photosensitivity {
+        fade.attack = photosensitivity.attack_duration(fade.attack, fade.release);
+    }
+
+    let screens = world
+        .query_filtered::<Entity, With<ScreenFlash>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    if screens.is_empty() {
+        warn_once!("Screen fade sent with no `ScreenFlash` to fade");
+    }
+    for entity in screens {
+        let mut entity = world.entity_mut(entity);
+        let merged = entity.get::<ScreenFade>().is_some_and(|active| {
+            active.flash && photosensitivity.is_some_and(|p| p.merges(active.elapsed))
+        });
+        if !merged {
+            entity.insert(fade);
+        }
+    }
+}
+
+fn screen_fade(
+    mut commands: Commands,
+    mut fades: Query<(Entity, &mut ScreenFade, &mut ScreenFlash)>,
+    photosensitivity: Option<Res<PhotosensitivitySettings>>,
+    time: Res<Time>,
+) {
+    for (entity, mut fade, mut flash) in fades.iter_mut() {
+        fade.elapsed += time.delta();
+
+        let photosensitivity = photosensitivity.as_deref().filter(|_| fade.flash);
+        let mut intensity = fade.intensity();
+        if let Some(photosensitivity) = photosensitivity {
+            intensity = photosensitivity.cap(intensity);
+        }
+
+        flash.color = fade.color.to_linear().to_vec4();
+        flash.intensity = intensity;
+        // linger until the interval passes, so that later flashes can merge into this one
+        if fade.finished() && !photosensitivity.is_some_and(|p| p.merges(fade.elapsed)) {
+            flash.intensity = 0.;
+            commands.entity(entity).remove::<ScreenFade>();
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::camera::MainCamera;
+use bevy::ecs::component::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Frames the [`MainCamera`] on the weighted center of every entity with a [`FrameWeight`].
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct FrameGroup;
+
+/// Includes an entity in the [`FrameGroup`].
+///
+/// The weight eases in over `ramp` when added, and eases out over `ramp` when removed, so targets
+/// entering or leaving the group do not cause the camera to pop. A removed weight eases out at the
+/// target's last position, so that despawned targets fade out as well.
+#[derive(Debug, Clone, Copy, Component)]
+#[component(on_remove = Self::fade_out)]
+pub struct FrameWeight {
+    pub weight: f32,
+    pub ramp: Duration,
+    elapsed: Duration,
+}
+
+impl FrameWeight {
+    pub fn new(weight: f32) -> Self {
+        Self::with_ramp(weight, Duration::from_millis(500))
+    }
+
+    pub fn with_ramp(weight: f32, ramp: Duration) -> Self {
+        Self {
+            weight,
+            ramp,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// The weight after easing.
+    pub fn current(&self) -> f32 {
+        self.weight * ramp(self.elapsed, self.ramp)
+    }
+
+    fn fade_out(mut world: DeferredWorld, ctx: HookContext) {
+        let entity = world.entity(ctx.entity);
+        let (Some(weight), Some(transform)) = (
+            entity.get::<FrameWeight>().copied(),
+            entity.get::<GlobalTransform>(),
+        ) else {
+            return;
+        };
+        let position = transform.translation().xy();
+
+        if weight.current() <= 0. {
+            return;
+        }
+
+        // kept outside of the entity, which is usually being despawned
+        if let Some(mut fading) = world.get_resource_mut::<FadingFrameWeights>() {
+            fading.0.push(FadingFrameWeight {
+                position,
+                weight: weight.weight,
+                ramp: weight.ramp,
+                remaining: weight.elapsed.min(weight.ramp),
+            });
+        }
+    }
+}
+
+/// The weights of removed [`FrameWeight`]s, easing out at their last position.
+#[derive(Debug, Default, Resource)]
+pub(crate) struct FadingFrameWeights(Vec<FadingFrameWeight>);
+
+#[derive(Debug, Clone, Copy)]
+struct FadingFrameWeight {
+    position: Vec2,
+    weight: f32,
+    ramp: Duration,
+    remaining: Duration,
+}
+
+impl FadingFrameWeight {
+    fn current(&self) -> f32 {
+        self.weight * ramp(self.remaining, self.ramp)
+    }
+}
+
+fn ramp(elapsed: Duration, ramp: Duration) -> f32 {
+    if ramp.is_zero() {
+        return 1.;
+    }
+
+    EaseFunction::SmoothStep.sample_clamped(elapsed.as_secs_f32() / ramp.as_secs_f32())
+}
+
+pub(crate) fn ramp_frame_weights(
+    mut weights: Query<&mut FrameWeight>,
+    mut fading: ResMut<FadingFrameWeights>,
+    time: Res<Time>,
+) {
+    for mut weight in weights.iter_mut() {
+        if weight.elapsed < weight.ramp {
+            weight.elapsed += time.delta();
+        }
+    }
+
+    fading.0.retain_mut(|weight| {
+        weight.remaining = weight.remaining.saturating_sub(time.delta());
+        !weight.remaining.is_zero()
+    });
+}
+
+pub(crate) fn frame_group(
+    camera: Option<Single<&mut Transform, (With<MainCamera>, With<FrameGroup>)>>,
+    weights: Query<(&GlobalTransform, &FrameWeight)>,
+    fading: Res<FadingFrameWeights>,
+) {
+    let Some(mut camera) = camera else {
+        return;
+    };
+
+    let (sum, weighted) = weights
+        .iter()
+        .map(|(t, w)| (t.translation().xy(), w.current()))
+        .chain(fading.0.iter().map(|w| (w.position, w.current())))
+        .fold((0., Vec2::ZERO), |(sum, weighted), (position, w)| {
+            (sum + w, weighted + position * w)
+        });
+
+    if sum > f32::EPSILON {
+        let center = weighted / sum;
+        camera.translation = center.extend(camera.translation.z);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::camera::{CameraSystem, MainCamera};
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// A debug fly camera for the [`MainCamera`], enabled through the [`FreeCamera`] resource.
+///
+/// Pan with WASD or the arrow keys, zoom with the scroll wheel, and drag with the middle mouse
+/// button. [`CameraSystem::UpdateCamera`] is suspended while the free camera is enabled, so
+/// bindings, anchors, and rooms resume where they left off once it is disabled.
+pub struct FreeCameraPlugin;
+
+impl Plugin for FreeCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FreeCamera>()
+            .add_systems(
+                Update,
+                (toggle_free_camera, free_camera.run_if(free_camera_enabled)).chain(),
+            )
+            .configure_sets(
+                PostUpdate,
+                CameraSystem::UpdateCamera.run_if(not(free_camera_enabled)),
+            )
+            .configure_sets(
+                FixedPostUpdate,
+                CameraSystem::UpdateCamera.run_if(not(free_camera_enabled)),
+            );
+    }
+}
+
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct FreeCamera {
+    pub enabled: bool,
+    /// Toggles `enabled` when pressed.
+    pub toggle_key: Option<KeyCode>,
+    /// Pan speed in screen heights per second.
+    pub pan_speed: f32,
+    /// The fraction of the scale zoomed per scroll line.
+    pub zoom_speed: f32,
+}
+
+impl Default for FreeCamera {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_key: Some(KeyCode::F2),
+            pan_speed: 1.,
+            zoom_speed: 0.1,
+        }
+    }
+}
+
+pub fn free_camera_enabled(free_camera: Option<Res<FreeCamera>>) -> bool {
+    free_camera.is_some_and(|free_camera| free_camera.enabled)
+}
+
+fn toggle_free_camera(mut free_camera: ResMut<FreeCamera>, keys: Res<ButtonInput<KeyCode>>) {
+    if free_camera
+        .toggle_key
+        .is_some_and(|key| keys.just_pressed(key))
+    {
+        free_camera.enabled = !free_camera.enabled;
+    }
+}
+
+fn free_camera(
+    camera: Option<Single<(&mut Transform, &mut Projection), With<MainCamera>>>,
+    window: Option<Single<&Window, With<PrimaryWindow>>>,
+    free_camera: Res<FreeCamera>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    motion: Res<AccumulatedMouseMotion>,
+    scroll: Res<AccumulatedMouseScroll>,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut projection)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = projection.as_mut() else {
+        return;
+    };
+    let view = ortho.area.size();
+
+    let direction = Vec2::new(
+        keys.any_pressed([KeyCode::KeyD, KeyCode::ArrowRight]) as i32 as f32
+            - keys.any_pressed([KeyCode::KeyA, KeyCode::ArrowLeft]) as i32 as f32,
+        keys.any_pressed([KeyCode::KeyW, KeyCode::ArrowUp]) as i32 as f32
+            - keys.any_pressed([KeyCode::KeyS, KeyCode::ArrowDown]) as i32 as f32,
+    )
+    .normalize_or_zero();
+    let mut delta = direction * free_camera.pan_speed * view.y * time.delta_secs();
+
+    if mouse.pressed(MouseButton::Middle) {
+        if let Some(window) = window {
+            let window = window.size();
+            if window.cmpgt(Vec2::ZERO).all() {
+                // screen y points down
+                delta += motion.delta * Vec2::new(-1., 1.) * view / window;
+            }
+        }
+    }
+
+    transform.translation += delta.extend(0.);
+
+    let lines = match scroll.unit {
+        MouseScrollUnit::Line => scroll.delta.y,
+        MouseScrollUnit::Pixel => scroll.delta.y / 16.,
+    };
+    if lines != 0. {
+        ortho.scale = (ortho.scale * (1. - free_camera.zoom_speed).powf(lines)).max(0.01);
+    }
+}
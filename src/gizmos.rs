@@ -0,0 +1,47 @@
+use crate::camera::MainCamera;
+use bevy::gizmos::config::GizmoConfigStore;
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+
+/// Routes every [`Gizmos`] config group to the [`MainCamera`], so gizmos are drawn into the low
+/// resolution canvas alongside the world content they annotate.
+pub struct PixelGizmosPlugin;
+
+impl Plugin for PixelGizmosPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PixelGizmos>()
+            .add_systems(PostUpdate, apply_pixel_gizmos);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct PixelGizmos {
+    /// Line width in canvas pixels, rounded to whole pixels.
+    pub line_width: f32,
+}
+
+impl Default for PixelGizmos {
+    fn default() -> Self {
+        Self { line_width: 1. }
+    }
+}
+
+fn apply_pixel_gizmos(
+    settings: Res<PixelGizmos>,
+    camera: Option<Single<Option<&RenderLayers>, With<MainCamera>>>,
+    mut store: ResMut<GizmoConfigStore>,
+) {
+    let Some(layers) = camera.map(|c| c.into_inner().cloned().unwrap_or_default()) else {
+        return;
+    };
+    let width = settings.line_width.round().max(1.);
+
+    for (_, config, _) in store.bypass_change_detection().iter_mut() {
+        // The canvas is the render target, so pixels are canvas pixels.
+        config.line.width = width;
+        config.line.perspective = false;
+        if config.render_layers != layers {
+            config.render_layers = layers.clone();
+        }
+    }
+}
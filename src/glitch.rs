@@ -1,20 +1,56 @@
+use crate::accessibility::{PhotosensitivitySettings, ReducedMotion, motion_factor};
+use crate::effect_target::{EffectTarget, EffectTargetCommands};
 use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use crate::replay::{OptixCommand, record_command};
 use bevy::asset::weak_handle;
 use bevy::render::extract_component::ExtractComponent;
-use bevy::render::render_resource::ShaderRef;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use bevy::sprite::{Material2d, Material2dPlugin};
 use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
 use bevy_tween::{BevyTweenRegisterSystems, component_tween_system, prelude::Interpolator};
+use serde::Deserialize;
+use std::time::Duration;
 
 pub const GLITCH_SHADER_HANDLE: Handle<Shader> =
     weak_handle!("b8f39834-a81e-4d5e-9ad9-043425f0afda");
+pub const GLITCH_SPRITE_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("4f0a6c1e-93d2-4b7a-8e15-2c6d0b9f7a31");
 
-pub struct GlitchPlugin;
+#[derive(Default)]
+pub struct GlitchPlugin {
+    /// Inserts [`GlitchSettings`] with no intensity on the targeted camera at startup, ready for
+    /// [`GlitchCommands::glitch_burst`].
+    ///
+    /// [`EffectTarget::Canvas`] leaves the high resolution layer unaffected.
+    pub target: Option<EffectTarget>,
+}
+
+impl GlitchPlugin {
+    pub fn with_target(mut self, target: EffectTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
 
 impl Plugin for GlitchPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(PostProcessPlugin::<GlitchSettings>::default())
+        app.register_type::<GlitchSettings>()
+            .add_plugins(PostProcessPlugin::<GlitchSettings>::default())
             .add_tween_systems(component_tween_system::<TweenGlitch>())
-            .add_systems(Update, tween_glitch);
+            .add_systems(Update, (glitch_burst, tween_glitch).chain());
+
+        if let Some(target) = self.target {
+            app.add_systems(PostStartup, move |mut commands: Commands| {
+                commands.insert_effect(
+                    target,
+                    (GlitchSettings::from_intensity(0.), GlitchIntensity(0.)),
+                );
+            });
+        }
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
 
         load_internal_asset!(
             app,
@@ -22,10 +58,20 @@ impl Plugin for GlitchPlugin {
             "shaders/glitch.wgsl",
             Shader::from_wgsl
         );
+        load_internal_asset!(
+            app,
+            GLITCH_SPRITE_SHADER_HANDLE,
+            "shaders/glitch_sprite.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.add_plugins(Material2dPlugin::<GlitchSprite>::default())
+            .add_systems(Update, tween_glitch_sprite);
     }
 }
 
-#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+#[derive(Debug, Component, Clone, Copy, ExtractComponent, ShaderType, Reflect, Deserialize)]
+#[serde(default)]
 pub struct GlitchSettings {
     pub shake_power: f32,
     pub shake_rate: f32,
@@ -33,6 +79,13 @@ pub struct GlitchSettings {
     pub shake_block_size: f32,
     pub shake_color_rate: f32,
     pub intensity: f32,
+    // WebGL2 structs must be 16 byte aligned, wherever `SIXTEEN_BYTE_ALIGNMENT` is defined. The
+    // padding is public so that struct literals may end with `..default()` on every target.
+    #[cfg(all(feature = "webgl2", target_arch = "wasm32", not(feature = "webgpu")))]
+    #[doc(hidden)]
+    #[reflect(ignore)]
+    #[serde(skip)]
+    pub _webgl2_padding: Vec2,
 }
 
 impl Default for GlitchSettings {
@@ -44,6 +97,8 @@ impl Default for GlitchSettings {
             shake_block_size: 30.5,
             shake_color_rate: 0.01,
             intensity: 0.5,
+            #[cfg(all(feature = "webgl2", target_arch = "wasm32", not(feature = "webgpu")))]
+            _webgl2_padding: Vec2::ZERO,
         }
     }
 }
@@ -93,8 +148,176 @@ impl Interpolator for TweenGlitch {
     }
 }
 
-fn tween_glitch(mut glitch_query: Query<(&mut GlitchSettings, &GlitchIntensity)>) {
+/// Drives the [`GlitchIntensity`] from `intensity` down to `0` over `duration`, removing itself
+/// once finished.
+///
+/// With [`PhotosensitivitySettings::gentle_fades`], the burst first rises over `duration`.
+#[derive(Debug, Clone, Component)]
+#[require(GlitchIntensity)]
+pub struct GlitchBurst {
+    pub intensity: f32,
+    duration: Duration,
+    elapsed: Duration,
+}
+
+impl GlitchBurst {
+    pub fn new(intensity: f32, duration: Duration) -> Self {
+        Self {
+            intensity,
+            duration,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// The burst's envelope in the range `[0, 1]`, rising over `attack`.
+    fn envelope(&self, attack: Duration) -> f32 {
+        if self.elapsed < attack {
+            self.elapsed.as_secs_f32() / attack.as_secs_f32()
+        } else if self.duration.is_zero() {
+            0.
+        } else {
+            1. - ((self.elapsed - attack).as_secs_f32() / self.duration.as_secs_f32()).min(1.)
+        }
+    }
+}
+
+pub trait GlitchCommands {
+    /// Inserts a [`GlitchBurst`] on every entity with [`GlitchSettings`].
+    ///
+    /// Bursts within the [`PhotosensitivitySettings::min_flash_interval`] of the previous burst
+    /// are merged into it, raising its intensity rather than restarting it.
+    fn glitch_burst(&mut self, intensity: f32, duration: Duration);
+}
+
+impl GlitchCommands for Commands<'_, '_> {
+    fn glitch_burst(&mut self, intensity: f32, duration: Duration) {
+        self.queue(move |world: &mut World| {
+            record_command(
+                world,
+                OptixCommand::GlitchBurst {
+                    intensity,
+                    duration,
+                },
+            );
+            let photosensitivity = world.get_resource::<PhotosensitivitySettings>().copied();
+            let screens = world
+                .query_filtered::<Entity, With<GlitchSettings>>()
+                .iter(world)
+                .collect::<Vec<_>>();
+            for entity in screens {
+                let mut entity = world.entity_mut(entity);
+                if let (Some(mut active), Some(photosensitivity)) =
+                    (entity.get_mut::<GlitchBurst>(), photosensitivity)
+                {
+                    if photosensitivity.merges(active.elapsed) {
+                        active.intensity = active.intensity.max(intensity);
+                        continue;
+                    }
+                }
+                entity.insert(GlitchBurst::new(intensity, duration));
+            }
+        });
+    }
+}
+
+fn glitch_burst(
+    mut commands: Commands,
+    mut bursts: Query<(Entity, &mut GlitchBurst, &mut GlitchIntensity)>,
+    photosensitivity: Option<Res<PhotosensitivitySettings>>,
+    time: Res<Time>,
+) {
+    let photosensitivity = photosensitivity.as_deref();
+    for (entity, mut burst, mut intensity) in bursts.iter_mut() {
+        burst.elapsed += time.delta();
+
+        let attack = photosensitivity.map_or(Duration::ZERO, |p| {
+            p.attack_duration(Duration::ZERO, burst.duration)
+        });
+        intensity.0 = burst.intensity * burst.envelope(attack);
+
+        // linger until the interval passes, so that later bursts can merge into this one
+        let finished = burst.elapsed >= attack + burst.duration
+            && !photosensitivity.is_some_and(|p| p.merges(burst.elapsed));
+        if finished {
+            intensity.0 = 0.;
+            commands.entity(entity).remove::<GlitchBurst>();
+        }
+    }
+}
+
+fn tween_glitch(
+    mut glitch_query: Query<(&mut GlitchSettings, &GlitchIntensity)>,
+    photosensitivity: Option<Res<PhotosensitivitySettings>>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+) {
     for (mut settings, intensity) in glitch_query.iter_mut() {
-        settings.intensity = intensity.0;
+        settings.intensity = limit_glitch(
+            intensity.0,
+            photosensitivity.as_deref(),
+            reduced_motion.as_deref(),
+        );
+    }
+}
+
+/// Glitches an individual sprite's `texture` with [`GlitchSettings`].
+///
+/// Draw with a [`Mesh2d`] and [`MeshMaterial2d<GlitchSprite>`]. A [`GlitchIntensity`] on the
+/// entity drives the material's intensity, so sprites tween like the screen does.
+#[derive(Debug, Clone, Asset, TypePath, AsBindGroup)]
+pub struct GlitchSprite {
+    #[uniform(0)]
+    pub settings: GlitchSettings,
+    #[texture(1)]
+    #[sampler(2)]
+    pub texture: Handle<Image>,
+}
+
+impl GlitchSprite {
+    pub fn new(texture: Handle<Image>) -> Self {
+        Self {
+            settings: GlitchSettings::default(),
+            texture,
+        }
+    }
+
+    pub fn with_settings(mut self, settings: GlitchSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+}
+
+impl Material2d for GlitchSprite {
+    fn fragment_shader() -> ShaderRef {
+        GLITCH_SPRITE_SHADER_HANDLE.into()
     }
+
+    fn alpha_mode(&self) -> bevy::sprite::AlphaMode2d {
+        bevy::sprite::AlphaMode2d::Blend
+    }
+}
+
+fn tween_glitch_sprite(
+    sprites: Query<(&MeshMaterial2d<GlitchSprite>, &GlitchIntensity), Changed<GlitchIntensity>>,
+    mut materials: ResMut<Assets<GlitchSprite>>,
+    photosensitivity: Option<Res<PhotosensitivitySettings>>,
+    reduced_motion: Option<Res<ReducedMotion>>,
+) {
+    for (material, intensity) in sprites.iter() {
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.settings.intensity = limit_glitch(
+                intensity.0,
+                photosensitivity.as_deref(),
+                reduced_motion.as_deref(),
+            );
+        }
+    }
+}
+
+fn limit_glitch(
+    intensity: f32,
+    photosensitivity: Option<&PhotosensitivitySettings>,
+    reduced_motion: Option<&ReducedMotion>,
+) -> f32 {
+    let intensity = intensity * motion_factor(reduced_motion);
+    photosensitivity.map_or(intensity, |p| p.cap(intensity))
 }
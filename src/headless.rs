@@ -0,0 +1,48 @@
+//! Helpers for running camera logic without a window or GPU.
+//!
+//! The camera systems tolerate the [`MainCamera`](crate::camera::MainCamera) being despawned or
+//! duplicated mid-game, skipping their work until exactly one camera exists again:
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_optix::{camera::MainCamera, headless::OptixTestAppExt};
+//! let mut app = App::new();
+//! app.add_optix_test_plugins();
+//! let camera = app.world_mut().spawn((MainCamera, Transform::default())).id();
+//! app.update();
+//!
+//! app.world_mut().despawn(camera);
+//! app.update();
+//!
+//! app.world_mut().spawn((MainCamera, Transform::default()));
+//! app.world_mut().spawn((MainCamera, Transform::default()));
+//! app.update();
+//! ```
+
+use crate::{camera::CameraAnimationPlugin, shake::ScreenShakePlugin, zorder::ZOrderPlugin};
+use bevy::{prelude::*, time::TimeUpdateStrategy, transform::TransformPlugin};
+use std::time::Duration;
+
+/// The fixed frame time used by [`OptixTestAppExt::add_optix_test_plugins`].
+pub const TEST_FRAME_TIME: Duration = Duration::from_nanos(16_666_667);
+
+pub trait OptixTestAppExt {
+    /// Adds the minimal set of plugins required to run the camera, shake, and zorder systems
+    /// headlessly.
+    ///
+    /// [`Time`] advances by [`TEST_FRAME_TIME`] every update so results are deterministic.
+    fn add_optix_test_plugins(&mut self) -> &mut Self;
+}
+
+impl OptixTestAppExt for App {
+    fn add_optix_test_plugins(&mut self) -> &mut Self {
+        self.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            CameraAnimationPlugin::default(),
+            ScreenShakePlugin::default(),
+            ZOrderPlugin,
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(TEST_FRAME_TIME))
+    }
+}
@@ -0,0 +1,211 @@
+use crate::camera::{CameraSystem, MainCamera};
+use crate::shake::ShakeSystem;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Records the [`MainCamera`]'s recent path into the [`CameraHistory`], which can be played
+/// backwards with [`RewindCommands::rewind_camera`].
+pub struct CameraHistoryPlugin {
+    /// How much history is kept.
+    pub length: Duration,
+}
+
+impl Default for CameraHistoryPlugin {
+    fn default() -> Self {
+        Self {
+            length: Duration::from_secs(10),
+        }
+    }
+}
+
+impl Plugin for CameraHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(CameraHistory::new(self.length))
+            .add_systems(
+                PostUpdate,
+                (play_rewind, record_camera_history)
+                    .chain()
+                    .after(CameraSystem::UpdateCamera)
+                    .before(ShakeSystem::Shake)
+                    .before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+/// A recorded frame of the [`MainCamera`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraSample {
+    /// The elapsed [`Time`] in seconds when the sample was recorded.
+    pub time: f64,
+    pub translation: Vec3,
+    /// The orthographic projection's scale.
+    pub scale: f32,
+}
+
+impl CameraSample {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            time: self.time + (other.time - self.time) * t as f64,
+            translation: self.translation.lerp(other.translation, t),
+            scale: self.scale.lerp(other.scale, t),
+        }
+    }
+}
+
+/// A ring buffer of the [`MainCamera`]'s recent [`CameraSample`]s, oldest first.
+///
+/// The camera's motion is recorded before shakes are applied, and is not recorded while
+/// rewinding.
+#[derive(Debug, Clone, Resource)]
+pub struct CameraHistory {
+    samples: VecDeque<CameraSample>,
+    pub length: Duration,
+}
+
+impl CameraHistory {
+    pub fn new(length: Duration) -> Self {
+        Self {
+            samples: VecDeque::new(),
+            length,
+        }
+    }
+
+    pub fn samples(&self) -> impl DoubleEndedIterator<Item = &CameraSample> {
+        self.samples.iter()
+    }
+
+    /// The samples recorded in the last `duration`, oldest first.
+    pub fn recent(&self, duration: Duration) -> impl DoubleEndedIterator<Item = &CameraSample> {
+        let start = self
+            .samples
+            .back()
+            .map_or(0., |last| last.time - duration.as_secs_f64());
+        self.samples
+            .iter()
+            .filter(move |sample| sample.time >= start)
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    fn push(&mut self, sample: CameraSample) {
+        self.samples.push_back(sample);
+        while self
+            .samples
+            .front()
+            .is_some_and(|front| sample.time - front.time > self.length.as_secs_f64())
+        {
+            self.samples.pop_front();
+        }
+    }
+}
+
+/// Plays a recorded path of the [`MainCamera`] backwards, removing itself once the path ends.
+#[derive(Debug, Clone, Component)]
+pub struct CameraRewind {
+    /// Newest first.
+    path: Vec<CameraSample>,
+    elapsed: f64,
+}
+
+impl CameraRewind {
+    /// The sample `elapsed` seconds into the rewind.
+    fn sample(&self) -> Option<CameraSample> {
+        let newest = self.path.first()?;
+        let time = newest.time - self.elapsed;
+        let index = self.path.iter().position(|sample| sample.time <= time);
+        match index {
+            Some(0) => Some(*newest),
+            Some(index) => {
+                let (later, earlier) = (self.path[index - 1], self.path[index]);
+                let span = later.time - earlier.time;
+                let t = if span > 0. {
+                    ((later.time - time) / span) as f32
+                } else {
+                    1.
+                };
+                Some(later.lerp(&earlier, t))
+            }
+            None => self.path.last().copied(),
+        }
+    }
+
+    fn finished(&self) -> bool {
+        match (self.path.first(), self.path.last()) {
+            (Some(newest), Some(oldest)) => self.elapsed >= newest.time - oldest.time,
+            _ => true,
+        }
+    }
+}
+
+pub trait RewindCommands {
+    /// Plays the last `duration` of the [`CameraHistory`] backwards, at the speed it was recorded.
+    fn rewind_camera(&mut self, duration: Duration);
+}
+
+impl RewindCommands for Commands<'_, '_> {
+    fn rewind_camera(&mut self, duration: Duration) {
+        self.queue(move |world: &mut World| -> Result {
+            let Some(history) = world.get_resource::<CameraHistory>() else {
+                return Err(
+                    "the camera history is not recorded, add the `CameraHistoryPlugin`".into(),
+                );
+            };
+            let path = history.recent(duration).rev().copied().collect();
+
+            let camera = world
+                .query_filtered::<Entity, With<MainCamera>>()
+                .single(world)?;
+            world
+                .entity_mut(camera)
+                .insert(CameraRewind { path, elapsed: 0. });
+            Ok(())
+        });
+    }
+}
+
+fn record_camera_history(
+    camera: Option<Single<(&Transform, &Projection), (With<MainCamera>, Without<CameraRewind>)>>,
+    mut history: ResMut<CameraHistory>,
+    time: Res<Time>,
+) {
+    let Some((transform, projection)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    history.push(CameraSample {
+        time: time.elapsed_secs_f64(),
+        translation: transform.translation,
+        scale: match projection {
+            Projection::Orthographic(ortho) => ortho.scale,
+            _ => 1.,
+        },
+    });
+}
+
+fn play_rewind(
+    mut commands: Commands,
+    camera: Option<
+        Single<(Entity, &mut Transform, &mut Projection, &mut CameraRewind), With<MainCamera>>,
+    >,
+    time: Res<Time>,
+) {
+    let Some((entity, mut transform, mut projection, mut rewind)) = camera.map(|c| c.into_inner())
+    else {
+        return;
+    };
+
+    rewind.elapsed += time.delta_secs_f64();
+    if let Some(sample) = rewind.sample() {
+        transform.translation = sample.translation;
+        if let Projection::Orthographic(ortho) = projection.as_mut() {
+            ortho.scale = sample.scale;
+        }
+    }
+
+    if rewind.finished() {
+        commands.entity(entity).remove::<CameraRewind>();
+    }
+}
@@ -0,0 +1,85 @@
+use crate::replay::{OptixCommand, record_command};
+use bevy::prelude::*;
+use bevy::time::TimeSystem;
+use std::time::Duration;
+
+/// Briefly freezes [`Time<Virtual>`] with [`HitstopCommands::hitstop`], selling the weight of an
+/// impact.
+pub struct HitstopPlugin;
+
+impl Plugin for HitstopPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Hitstop>()
+            .add_systems(First, tick_hitstop.after(TimeSystem));
+    }
+}
+
+/// The remaining duration of the active hitstop, measured in real time.
+///
+/// The hitstop freezes [`Time<Virtual>`] by zeroing its relative speed rather than pausing it, so
+/// the game may pause and unpause time freely during a hitstop. The previous speed is restored
+/// afterwards, unless the game changed the speed in the meantime.
+#[derive(Debug, Default, Clone, Copy, Resource)]
+pub struct Hitstop {
+    remaining: Duration,
+    /// The relative speed to restore, set while the hitstop owns the speed.
+    speed: Option<f32>,
+}
+
+impl Hitstop {
+    /// Freezes time for at least `duration`, extending the active hitstop if it is shorter.
+    pub fn freeze(&mut self, duration: Duration) {
+        self.remaining = self.remaining.max(duration);
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+
+    pub fn active(&self) -> bool {
+        !self.remaining.is_zero()
+    }
+}
+
+pub trait HitstopCommands {
+    /// Freezes [`Time<Virtual>`] for `duration`.
+    fn hitstop(&mut self, duration: Duration);
+}
+
+impl HitstopCommands for Commands<'_, '_> {
+    fn hitstop(&mut self, duration: Duration) {
+        self.queue(move |world: &mut World| {
+            record_command(world, OptixCommand::Hitstop(duration));
+            world.get_resource_or_init::<Hitstop>().freeze(duration);
+        });
+    }
+}
+
+fn tick_hitstop(
+    mut hitstop: ResMut<Hitstop>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+) {
+    if !hitstop.active() {
+        return;
+    }
+
+    let Some(speed) = hitstop.speed else {
+        // the first frozen frame is the next one
+        hitstop.speed = Some(virtual_time.relative_speed());
+        virtual_time.set_relative_speed(0.);
+        return;
+    };
+
+    if virtual_time.relative_speed() != 0. {
+        // the game took over the speed, so the hitstop ends without restoring it
+        *hitstop = Hitstop::default();
+        return;
+    }
+
+    hitstop.remaining = hitstop.remaining.saturating_sub(real_time.delta());
+    if !hitstop.active() {
+        virtual_time.set_relative_speed(speed);
+        hitstop.speed = None;
+    }
+}
@@ -0,0 +1,115 @@
+//! Converts physics collisions into screen shake trauma.
+//!
+//! Enabled with the `avian` or `rapier` feature.
+
+use crate::ease::OptixEase;
+use crate::shake::AddTrauma;
+use bevy::prelude::*;
+
+pub struct ImpactTraumaPlugin;
+
+impl Plugin for ImpactTraumaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AddTrauma>();
+        #[cfg(feature = "avian")]
+        app.add_systems(Update, avian_impact_trauma);
+        #[cfg(feature = "rapier")]
+        app.add_systems(Update, rapier_impact_trauma);
+    }
+}
+
+/// Applies trauma when this entity collides, scaled by the strength of the impact.
+///
+/// The impact is normalized between `min_impact` and `max_impact`, then mapped through `mapping`
+/// and scaled by `max_trauma`. Impacts below `min_impact` are ignored.
+///
+/// With `avian` the impact is the total normal impulse of the contact. With `rapier` it is the
+/// total contact force, which requires `ActiveEvents::CONTACT_FORCE_EVENTS` on the collider.
+#[derive(Component)]
+pub struct ImpactTrauma {
+    pub min_impact: f32,
+    pub max_impact: f32,
+    pub max_trauma: f32,
+    pub mapping: OptixEase,
+}
+
+impl ImpactTrauma {
+    pub fn new(min_impact: f32, max_impact: f32, max_trauma: f32) -> Self {
+        Self {
+            min_impact,
+            max_impact,
+            max_trauma,
+            mapping: EaseFunction::Linear.into(),
+        }
+    }
+
+    pub fn with_mapping(mut self, mapping: impl Into<OptixEase>) -> Self {
+        self.mapping = mapping.into();
+        self
+    }
+
+    pub fn trauma(&self, impact: f32) -> Option<f32> {
+        if impact < self.min_impact {
+            return None;
+        }
+
+        let t = ((impact - self.min_impact)
+            / (self.max_impact - self.min_impact).max(f32::EPSILON))
+        .clamp(0., 1.);
+        Some(self.max_trauma * self.mapping.sample(t))
+    }
+}
+
+/// Sends the largest trauma of the two entities, so a pair of marked entities does not shake
+/// twice.
+fn send_impact(
+    impacts: &Query<&ImpactTrauma>,
+    entities: [Entity; 2],
+    impact: f32,
+    writer: &mut EventWriter<AddTrauma>,
+) {
+    if let Some(trauma) = impacts
+        .iter_many(entities)
+        .filter_map(|mapping| mapping.trauma(impact))
+        .reduce(f32::max)
+    {
+        writer.write(AddTrauma(trauma));
+    }
+}
+
+#[cfg(feature = "avian")]
+fn avian_impact_trauma(
+    mut started: EventReader<avian2d::prelude::CollisionStarted>,
+    collisions: avian2d::prelude::Collisions,
+    impacts: Query<&ImpactTrauma>,
+    mut writer: EventWriter<AddTrauma>,
+) {
+    for avian2d::prelude::CollisionStarted(a, b) in started.read() {
+        let Some(contacts) = collisions.get(*a, *b) else {
+            continue;
+        };
+
+        send_impact(
+            &impacts,
+            [*a, *b],
+            contacts.total_normal_impulse_magnitude(),
+            &mut writer,
+        );
+    }
+}
+
+#[cfg(feature = "rapier")]
+fn rapier_impact_trauma(
+    mut forces: EventReader<bevy_rapier2d::prelude::ContactForceEvent>,
+    impacts: Query<&ImpactTrauma>,
+    mut writer: EventWriter<AddTrauma>,
+) {
+    for event in forces.read() {
+        send_impact(
+            &impacts,
+            [event.collider1, event.collider2],
+            event.total_force_magnitude,
+            &mut writer,
+        );
+    }
+}
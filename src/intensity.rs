@@ -0,0 +1,55 @@
+use crate::glitch::GlitchIntensity;
+use crate::hitstop::Hitstop;
+use crate::shake::{Shake, ShakeSystem};
+use bevy::prelude::*;
+
+/// Maintains the [`PresentationIntensity`].
+pub struct PresentationIntensityPlugin;
+
+impl Plugin for PresentationIntensityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PresentationIntensity>()
+            .add_systems(PostUpdate, presentation_intensity.after(ShakeSystem::Shake));
+    }
+}
+
+/// How intense the presentation is this frame, for ducking music or driving rumble.
+///
+/// Aggregated from the largest [`Shake`] trauma, the largest [`GlitchIntensity`], and whether a
+/// [`Hitstop`] is active. Updated in [`PostUpdate`], so systems in [`Update`] read the previous
+/// frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Resource)]
+pub struct PresentationIntensity {
+    pub trauma: f32,
+    pub glitch: f32,
+    pub hitstop: bool,
+}
+
+impl PresentationIntensity {
+    /// The normalized intensity, in the range `[0, 1]`.
+    ///
+    /// A hitstop is always at full intensity.
+    pub fn value(&self) -> f32 {
+        if self.hitstop {
+            return 1.;
+        }
+        self.trauma.max(self.glitch).clamp(0., 1.)
+    }
+}
+
+fn presentation_intensity(
+    mut intensity: ResMut<PresentationIntensity>,
+    shakes: Query<&Shake>,
+    glitches: Query<&GlitchIntensity>,
+    hitstop: Option<Res<Hitstop>>,
+) {
+    let next = PresentationIntensity {
+        trauma: shakes.iter().map(Shake::trauma).fold(0., f32::max),
+        glitch: glitches
+            .iter()
+            .map(|glitch| glitch.0.clamp(0., 1.))
+            .fold(0., f32::max),
+        hitstop: hitstop.is_some_and(|hitstop| hitstop.active()),
+    };
+    intensity.set_if_neq(next);
+}
@@ -0,0 +1,74 @@
+use crate::camera::MainCamera;
+use bevy::prelude::*;
+
+/// Limits how fast the [`MainCamera`] may move, regardless of what is moving it.
+///
+/// Applied at the end of the follow logic, so binding, [`MoveTo`](crate::camera::MoveTo), and
+/// anchors all respect the limits. Useful when a target teleports. View effects, such as biases
+/// and recoil, are offsets on top of the limited translation and are not limited themselves.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct CameraKinematics {
+    /// Maximum speed in world units per second.
+    pub max_speed: f32,
+    /// Maximum acceleration in world units per second squared.
+    pub max_accel: f32,
+    velocity: Vec2,
+    previous: Option<Vec2>,
+}
+
+impl CameraKinematics {
+    pub fn new(max_speed: f32, max_accel: f32) -> Self {
+        Self {
+            max_speed,
+            max_accel,
+            velocity: Vec2::ZERO,
+            previous: None,
+        }
+    }
+
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Forgets the previous position, allowing the camera to jump on the next frame.
+    pub fn reset(&mut self) {
+        self.velocity = Vec2::ZERO;
+        self.previous = None;
+    }
+
+    /// Steps from the previous position towards `position` within the limits, returning the
+    /// limited position.
+    pub fn limit(&mut self, position: Vec2, dt: f32) -> Vec2 {
+        let Some(previous) = self.previous.filter(|_| dt > 0.) else {
+            self.previous = Some(position);
+            return position;
+        };
+
+        let delta = position - previous;
+        // The fastest speed from which the camera can still stop at its destination.
+        let stopping_speed = (2. * self.max_accel * delta.length()).sqrt();
+        let desired = (delta / dt).clamp_length_max(stopping_speed);
+
+        let accel = (desired - self.velocity).clamp_length_max(self.max_accel * dt);
+        let velocity = (self.velocity + accel).clamp_length_max(self.max_speed);
+        // Never step past the destination.
+        let step = (velocity * dt).clamp_length_max(delta.length());
+
+        let limited = previous + step;
+        self.velocity = step / dt;
+        self.previous = Some(limited);
+        limited
+    }
+}
+
+pub(crate) fn limit_kinematics(
+    camera: Option<Single<(&mut Transform, &mut CameraKinematics), With<MainCamera>>>,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut kinematics)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    let limited = kinematics.limit(transform.translation.xy(), time.delta_secs());
+    transform.translation = limited.extend(transform.translation.z);
+}
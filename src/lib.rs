@@ -1,10 +1,81 @@
 #![allow(clippy::type_complexity)]
 
+pub mod accessibility;
 pub mod anchor;
+pub mod bias;
+pub mod bounds;
+pub mod calibration;
 pub mod camera;
+pub mod color_temperature;
+pub mod colorblind;
+#[cfg(feature = "console")]
+pub mod console;
+pub mod constraints;
+pub mod damage;
 pub mod debug;
+pub mod ease;
+pub mod effect_target;
+pub mod events;
+pub mod flash;
+pub mod framing;
+pub mod free_camera;
+#[cfg(feature = "gizmos")]
+pub mod gizmos;
 pub mod glitch;
+pub mod headless;
+pub mod history;
+pub mod hitstop;
+#[cfg(any(feature = "avian", feature = "rapier"))]
+pub mod impact;
+pub mod intensity;
+pub mod kinematics;
+pub mod motion_blur;
+pub mod networked;
 pub mod pixel_perfect;
+pub mod plugins;
 pub mod post_process;
+pub mod posterize;
+pub mod presets;
+pub mod recoil;
+pub mod refraction;
+pub mod replay;
+pub mod rooms;
+pub mod screenshot;
+#[cfg(feature = "script")]
+pub mod script;
 pub mod shake;
+pub mod shake_recording;
+pub mod smoothing;
+pub mod snapshot;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod timeline;
+pub mod vhs;
+pub mod virtual_camera;
+pub mod visible;
+pub mod zoom;
 pub mod zorder;
+
+/// The most commonly used items, imported with `use bevy_optix::prelude::*`.
+pub mod prelude {
+    pub use crate::camera::{
+        Binded, CameraAnimationPlugin, CameraMoveCommands, CameraOffset, CameraOffsetMode,
+        MainCamera, MoveTo,
+    };
+    pub use crate::debug::{
+        DebugCircle, DebugComponentAppExt, DebugLayer, DebugPlugin, DebugRect, debug_res,
+        debug_single,
+    };
+    pub use crate::ease::OptixEase;
+    pub use crate::effect_target::{EffectTarget, EffectTargetCommands};
+    pub use crate::events::{OptixEffectEvent, OptixEffectEventPlugin};
+    pub use crate::flash::{FlashCommands, ScreenFlashPlugin};
+    pub use crate::glitch::{GlitchPlugin, GlitchSettings};
+    pub use crate::hitstop::{HitstopCommands, HitstopPlugin};
+    pub use crate::pixel_perfect::{CanvasDimensions, PixelPerfectPlugin};
+    pub use crate::plugins::OptixPlugins;
+    pub use crate::post_process::prelude::{PostProcessCommand, PostProcessPlugin};
+    pub use crate::shake::{ScreenShakePlugin, Shake, ShakeSettings, TraumaCommands};
+    pub use crate::zoom::ZoomCommands;
+    pub use crate::zorder::{YOrigin, ZOrder, ZOrderPlugin};
+}
@@ -0,0 +1,120 @@
+use crate::camera::MainCamera;
+use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use bevy::asset::weak_handle;
+use bevy::ecs::query::QueryItem;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_resource::ShaderRef;
+use bevy::transform::TransformSystem;
+use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
+
+pub const MOTION_BLUR_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("e6a9c3f1-8d2b-4f47-b05e-31c7d4a8e9f6");
+
+pub struct CameraMotionBlurPlugin;
+
+impl Plugin for CameraMotionBlurPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(PostProcessPlugin::<CameraMotionBlurSettings>::default())
+            .add_systems(
+                PostUpdate,
+                track_camera_motion.after(TransformSystem::TransformPropagate),
+            );
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
+
+        load_internal_asset!(
+            app,
+            MOTION_BLUR_SHADER_HANDLE,
+            "shaders/motion_blur.wgsl",
+            Shader::from_wgsl
+        );
+    }
+}
+
+/// Blurs the screen along the [`MainCamera`]'s motion, e.g. during fast pans and dashes.
+///
+/// The direction is extracted from the camera's [`CameraMotion`] every frame.
+#[derive(Debug, Component, Clone, Copy, ShaderType)]
+#[require(CameraMotion)]
+pub struct CameraMotionBlurSettings {
+    direction: Vec2,
+    /// The length of the blur relative to the camera's motion over a frame.
+    pub strength: f32,
+    /// The longest blur, in UVs, which prevents teleports from smearing the screen.
+    pub max_length: f32,
+}
+
+impl Default for CameraMotionBlurSettings {
+    fn default() -> Self {
+        Self::new(1.)
+    }
+}
+
+impl CameraMotionBlurSettings {
+    pub fn new(strength: f32) -> Self {
+        Self {
+            direction: Vec2::ZERO,
+            strength,
+            max_length: 0.05,
+        }
+    }
+}
+
+impl ExtractComponent for CameraMotionBlurSettings {
+    type QueryData = (&'static Self, &'static CameraMotion);
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component((settings, motion): QueryItem<'_, Self::QueryData>) -> Option<Self::Out> {
+        Some(Self {
+            direction: motion.delta,
+            ..*settings
+        })
+    }
+}
+
+impl PostProcessMaterial for CameraMotionBlurSettings {
+    fn fragment_shader() -> ShaderRef {
+        MOTION_BLUR_SHADER_HANDLE.into()
+    }
+
+    fn extract_every_frame() -> bool {
+        true
+    }
+}
+
+/// The camera's motion over the last frame, in UVs.
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct CameraMotion {
+    delta: Vec2,
+    previous: Option<Vec2>,
+}
+
+impl CameraMotion {
+    pub fn delta(&self) -> Vec2 {
+        self.delta
+    }
+}
+
+fn track_camera_motion(
+    mut cameras: Query<(&mut CameraMotion, &GlobalTransform, &Projection), With<MainCamera>>,
+) {
+    for (mut motion, transform, projection) in cameras.iter_mut() {
+        let Projection::Orthographic(ortho) = projection else {
+            continue;
+        };
+
+        let translation = transform.translation().xy();
+        let view = ortho.area.size();
+        motion.delta = match motion.previous {
+            // UVs point down
+            Some(previous) if view.cmpgt(Vec2::ZERO).all() => {
+                (translation - previous) / view * Vec2::new(1., -1.)
+            }
+            _ => Vec2::ZERO,
+        };
+        motion.previous = Some(translation);
+    }
+}
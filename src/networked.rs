@@ -0,0 +1,123 @@
+use crate::camera::{Binded, MainCamera, MoveTo, ResolvedCameraOffset};
+use crate::smoothing::decay;
+use bevy::ecs::component::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// The latest authoritative or predicted position of a networked entity, e.g. from a rollback or
+/// interpolation buffer.
+///
+/// Followed by [`NetworkedFollow`] instead of the entity's [`Transform`].
+#[derive(Debug, Clone, Copy, Component)]
+pub struct PredictedPosition {
+    position: Vec3,
+    /// The elapsed [`Time`] in seconds at which `position` was valid.
+    timestamp: f64,
+    velocity: Vec3,
+}
+
+impl PredictedPosition {
+    pub fn new(position: Vec3, timestamp: f64) -> Self {
+        Self {
+            position,
+            timestamp,
+            velocity: Vec3::ZERO,
+        }
+    }
+
+    /// Records a new sample, estimating the velocity from the previous sample.
+    ///
+    /// Samples older than the current sample are ignored.
+    pub fn set(&mut self, position: Vec3, timestamp: f64) {
+        let dt = timestamp - self.timestamp;
+        if dt < 0. {
+            return;
+        }
+        if dt > 0. {
+            self.velocity = (position - self.position) / dt as f32;
+        }
+        self.position = position;
+        self.timestamp = timestamp;
+    }
+
+    pub fn position(&self) -> Vec3 {
+        self.position
+    }
+
+    pub fn timestamp(&self) -> f64 {
+        self.timestamp
+    }
+
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// The position extrapolated to `now`, at most `limit` past the sample.
+    pub fn extrapolate(&self, now: f64, limit: Duration) -> Vec3 {
+        let age = (now - self.timestamp).clamp(0., limit.as_secs_f64());
+        self.position + self.velocity * age as f32
+    }
+}
+
+/// Follows the [`PredictedPosition`] of `target`, extrapolating between network updates and
+/// smoothly correcting mispredictions.
+#[derive(Debug, Clone, Copy, Component)]
+#[component(on_insert = on_insert_networked_follow)]
+pub struct NetworkedFollow {
+    pub target: Entity,
+    /// The longest time a sample is extrapolated, after which the camera waits for an update.
+    pub max_extrapolation: Duration,
+    /// The rate at which corrections are smoothed out.
+    pub correction_rate: f32,
+    /// Corrections larger than this distance snap instead of smoothing, e.g. after a respawn.
+    pub snap_distance: f32,
+    current: Option<Vec2>,
+}
+
+impl NetworkedFollow {
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            max_extrapolation: Duration::from_millis(250),
+            correction_rate: 10.,
+            snap_distance: 256.,
+            current: None,
+        }
+    }
+}
+
+fn on_insert_networked_follow(mut world: DeferredWorld, context: HookContext) {
+    world.commands().entity(context.entity).remove::<Binded>();
+}
+
+pub(crate) fn camera_networked_follow(
+    camera: Option<
+        Single<(&mut Transform, &mut NetworkedFollow), (With<MainCamera>, Without<MoveTo>)>,
+    >,
+    targets: Query<(&PredictedPosition, Option<&ResolvedCameraOffset>)>,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut follow)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    let Ok((predicted, offset)) = targets.get(follow.target) else {
+        warn_once!("Camera follows networked entity with no predicted position");
+        return;
+    };
+
+    let target = predicted
+        .extrapolate(time.elapsed_secs_f64(), follow.max_extrapolation)
+        .xy();
+    let position = match follow.current {
+        Some(current) if current.distance_squared(target) <= follow.snap_distance.powi(2) => {
+            decay(current, target, follow.correction_rate, time.delta_secs())
+        }
+        _ => target,
+    };
+    follow.current = Some(position);
+
+    let position = position + offset.map(|o| o.0).unwrap_or_default();
+    transform.translation = position.extend(transform.translation.z);
+}
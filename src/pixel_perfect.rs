@@ -1,25 +1,95 @@
-use super::camera::MainCamera;
+use super::camera::{MainCamera, single_or_warn};
 use bevy::prelude::*;
 use bevy::{
     image::ImageSamplerDescriptor,
+    platform::collections::HashMap,
     render::{
-        camera::RenderTarget,
+        camera::{RenderTarget, ScalingMode, Viewport},
         render_resource::{
             Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
         },
         view::RenderLayers,
     },
-    window::WindowResized,
+    sprite::{SpriteImageMode, TextureSlicer},
+    transform::TransformSystem,
+    window::{PrimaryWindow, WindowRef, WindowResized, WindowScaleFactorChanged},
 };
 
 pub const HIGH_RES_LAYER: RenderLayers = RenderLayers::layer(1);
 
+/// HDR render targets are not guaranteed to be renderable on WebGL2.
+const HDR: bool = !cfg!(all(feature = "webgl2", not(feature = "webgpu")));
+
 /// Determines the resolution of the [`MainCamera`].
-#[derive(Debug, Clone, Copy, Resource)]
+///
+/// Secondary canvases store their own dimensions as a component.
+#[derive(Debug, Clone, Copy, Resource, Component)]
 pub struct CanvasDimensions {
     pub width: u32,
     pub height: u32,
     pub pixel_scale: f32,
+    pub policy: PixelPolicy,
+}
+
+/// Determines which pixels the canvas is fit to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PixelPolicy {
+    /// Fills the window, ignoring the [`Window::scale_factor`].
+    #[default]
+    LogicalPixels,
+    /// Covers each canvas pixel with a whole number of physical pixels, so that the canvas stays
+    /// crisp on HiDPI displays at the cost of a slightly larger letterbox.
+    PhysicalPixels,
+}
+
+impl CanvasDimensions {
+    pub fn new(width: u32, height: u32, pixel_scale: f32) -> Self {
+        Self {
+            width,
+            height,
+            pixel_scale,
+            policy: PixelPolicy::default(),
+        }
+    }
+
+    pub fn with_policy(mut self, policy: PixelPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The scale of the [`OuterCamera`]'s view at which the canvas fills a `window` sized window,
+    /// in logical pixels.
+    ///
+    /// Returns `None` for empty windows and canvases, e.g. while minimized.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_optix::pixel_perfect::{CanvasDimensions, PixelPolicy};
+    /// let dimensions = CanvasDimensions::new(320, 180, 1.);
+    /// assert_eq!(dimensions.fit_scale(Vec2::new(1280., 720.), 1.), Some(4.));
+    /// assert_eq!(dimensions.fit_scale(Vec2::new(1280., 0.), 1.), None);
+    ///
+    /// let physical = dimensions.with_policy(PixelPolicy::PhysicalPixels);
+    /// assert_eq!(physical.fit_scale(Vec2::new(800., 450.), 1.5), Some(3. / 1.5));
+    /// ```
+    pub fn fit_scale(&self, window: Vec2, scale_factor: f32) -> Option<f32> {
+        let fit = |size: Vec2| (size.x / self.width as f32).min(size.y / self.height as f32);
+        let scale = match self.policy {
+            PixelPolicy::LogicalPixels => fit(window),
+            PixelPolicy::PhysicalPixels => {
+                let physical = fit(window * scale_factor);
+                // Canvases larger than the window can not be aligned.
+                let physical = if physical >= 1. {
+                    physical.floor()
+                } else {
+                    physical
+                };
+                physical / scale_factor
+            }
+        } / self.pixel_scale;
+
+        (scale.is_finite() && scale > 0.).then_some(scale)
+    }
 }
 
 /// Captures the `pixel_perfect::HIGH_RES_BACKGROUND_LAYER` and `pixel_perfect::HIGH_RES_LAYER`, rendering the [`Canvas`] texture generated from the
@@ -49,15 +119,101 @@ pub enum Scaling {
     Projection,
 }
 
+/// Renders the [`MainCanvas`] into a region of the primary window instead of the whole window,
+/// e.g. a game view surrounded by native resolution UI panels.
+///
+/// The canvas is fit to the region, which becomes the [`OuterCamera`]'s [`Viewport`].
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct CanvasViewport {
+    /// The region of the window in normalized coordinates, where `(0, 0)` is the top left.
+    pub rect: Rect,
+}
+
+impl Default for CanvasViewport {
+    fn default() -> Self {
+        Self {
+            rect: Rect::new(0., 0., 1., 1.),
+        }
+    }
+}
+
+impl CanvasViewport {
+    pub fn new(rect: Rect) -> Self {
+        Self { rect }
+    }
+
+    /// The region within a window of `physical_size`, in physical pixels.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_optix::pixel_perfect::CanvasViewport;
+    /// let viewport = CanvasViewport::new(Rect::new(0.15, 0., 0.85, 1.));
+    /// let rect = viewport.physical_rect(UVec2::new(1000, 500));
+    /// assert_eq!(rect, URect::new(150, 0, 850, 500));
+    /// ```
+    pub fn physical_rect(&self, physical_size: UVec2) -> URect {
+        let size = physical_size.as_vec2();
+        let min = (self.rect.min.clamp(Vec2::ZERO, Vec2::ONE) * size).round();
+        let max = (self.rect.max.clamp(Vec2::ZERO, Vec2::ONE) * size).round();
+        URect::from_corners(min.as_uvec2(), max.as_uvec2())
+    }
+}
+
+/// Configures the orthographic projection of the [`MainCamera`].
+///
+/// The projection is fixed to the [`CanvasDimensions`], so that at a scale of 1 a world unit
+/// maps to exactly one canvas pixel. The projection's scale is left untouched for zooming.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct CanvasProjection {
+    pub near: f32,
+    pub far: f32,
+    /// The point of the canvas, in normalized coordinates, that the camera's translation maps to.
+    pub viewport_origin: Vec2,
+}
+
+impl Default for CanvasProjection {
+    fn default() -> Self {
+        let ortho = OrthographicProjection::default_2d();
+        Self {
+            near: ortho.near,
+            far: ortho.far,
+            viewport_origin: ortho.viewport_origin,
+        }
+    }
+}
+
+/// Renders the [`MainCamera`] into a low resolution [`Canvas`].
+///
+/// The [`MainCamera`]'s projection is configured with the [`CanvasProjection`] resource.
 pub struct PixelPerfectPlugin(pub CanvasDimensions);
 
 impl Plugin for PixelPerfectPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(self.0)
+            .init_resource::<CanvasProjection>()
             //.insert_resource(AlignCanvasToCamera)
             //.insert_resource(Scaling::Projection)
+            .add_event::<WindowResized>()
+            .add_event::<WindowScaleFactorChanged>()
             .add_systems(PreStartup, setup_cameras)
-            .add_systems(First, (fit_canvas, resize_canvas, propagate_render_layers));
+            .add_systems(
+                First,
+                (
+                    fit_canvas,
+                    configure_projection.run_if(
+                        resource_changed::<CanvasProjection>
+                            .or(resource_changed::<CanvasDimensions>)
+                            // respawned cameras
+                            .or(any_match_filter::<Added<MainCamera>>),
+                    ),
+                    resize_canvas.run_if(resource_exists::<Assets<Image>>),
+                    assign_layers,
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                fit_canvas_border.before(TransformSystem::TransformPropagate),
+            );
         //.add_systems(
         //    PostUpdate,
         //    align_canvas_to_camera
@@ -76,30 +232,185 @@ impl Plugin for PixelPerfectPlugin {
     }
 }
 
+/// A sprite displaying the texture rendered by the cameras with the same [`CanvasId`].
 #[derive(Component)]
+#[require(CanvasId)]
 pub struct Canvas;
 
+/// The [`Canvas`] rendered by the [`MainCamera`] and displayed by the [`OuterCamera`].
+#[derive(Component)]
+#[require(Canvas)]
+pub struct MainCanvas;
+
+/// Pairs a [`Canvas`] with the cameras that render into it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub struct CanvasId(pub u32);
+
+impl CanvasId {
+    pub const MAIN: Self = Self(0);
+}
+
+/// A secondary [`Canvas`], e.g. a security camera feed, with its own resolution.
+///
+/// Rendered into by a [`canvas_camera`] with the same `id`.
+pub fn secondary_canvas(id: CanvasId, dimensions: CanvasDimensions) -> impl Bundle {
+    (
+        Canvas,
+        id,
+        dimensions,
+        Transform::from_scale(Vec3::splat(dimensions.pixel_scale)),
+    )
+}
+
+/// Displays the [`Canvas`] with the same `canvas` id in another window, e.g. a tool or editor
+/// viewport, fitting it to that window like the [`OuterCamera`] fits the [`MainCanvas`].
+///
+/// Spawned with [`window_canvas_camera`].
+#[derive(Debug, Clone, Copy, Component)]
+pub struct WindowCanvasCamera {
+    pub canvas: CanvasId,
+}
+
+/// A high resolution camera that displays the [`Canvas`] with the same `canvas` id in `window`.
+///
+/// The canvas should be placed on `layers` and the layers should not be shared with other
+/// windows, so that each window only displays its own canvas.
+pub fn window_canvas_camera(
+    window: WindowRef,
+    canvas: CanvasId,
+    layers: RenderLayers,
+) -> impl Bundle {
+    (
+        Camera2d,
+        Camera {
+            hdr: HDR,
+            order: 1,
+            target: RenderTarget::Window(window),
+            ..Default::default()
+        },
+        WindowCanvasCamera { canvas },
+        layers,
+        Msaa::Off,
+    )
+}
+
+/// A low resolution camera that renders into the [`Canvas`] with the same `id`.
+///
+/// Renders before the [`MainCamera`], so the canvas can be displayed in the main canvas.
+pub fn canvas_camera(id: CanvasId) -> impl Bundle {
+    (
+        Camera2d,
+        Camera {
+            hdr: HDR,
+            order: -1,
+            clear_color: ClearColorConfig::Custom(Color::BLACK),
+            ..Default::default()
+        },
+        id,
+        Msaa::Off,
+    )
+}
+
+/// Decorates the letterboxed region around the [`Canvas`], e.g. with CRT bezel art.
+///
+/// Centered on the canvas and resized with the window. The size is written to the [`Sprite`]'s
+/// `custom_size` if present, otherwise to the scale of the [`Transform`], which suits a shader
+/// driven border drawn on a unit [`Rectangle`] mesh.
+#[derive(Debug, Clone, Copy, Component)]
+#[require(Transform, Visibility, RenderLayers = HIGH_RES_LAYER)]
+pub struct CanvasBorder {
+    pub size: BorderSize,
+}
+
+impl CanvasBorder {
+    /// A 9-slice frame that extends `thickness` screen pixels past the edges of the canvas.
+    pub fn sliced(image: Handle<Image>, slicer: TextureSlicer, thickness: f32) -> impl Bundle {
+        (
+            Self {
+                size: BorderSize::Padding(thickness),
+            },
+            Sprite {
+                image,
+                image_mode: SpriteImageMode::Sliced(slicer),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Fills the window, covering the letterboxed region.
+    pub fn fill() -> Self {
+        Self {
+            size: BorderSize::Window,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderSize {
+    /// Extends past the edges of the canvas by a number of screen pixels.
+    Padding(f32),
+    /// Fills the window.
+    Window,
+}
+
+fn fit_canvas_border(
+    dimensions: Res<CanvasDimensions>,
+    outer: Query<&Projection, With<OuterCamera>>,
+    canvas: Query<&Transform, (With<MainCanvas>, Without<CanvasBorder>)>,
+    mut borders: Query<(&CanvasBorder, &mut Transform, Option<&mut Sprite>)>,
+) {
+    let (Some(outer), Some(canvas)) = (
+        single_or_warn(outer.single(), "OuterCamera"),
+        single_or_warn(canvas.single(), "MainCanvas"),
+    ) else {
+        return;
+    };
+    let Projection::Orthographic(projection) = outer else {
+        return;
+    };
+    let canvas_size =
+        Vec2::new(dimensions.width as f32, dimensions.height as f32) * dimensions.pixel_scale;
+
+    for (border, mut transform, sprite) in borders.iter_mut() {
+        let size = match border.size {
+            BorderSize::Padding(thickness) => canvas_size + 2. * thickness * projection.scale,
+            BorderSize::Window => projection.area.size(),
+        };
+
+        transform.translation = canvas.translation.xy().extend(transform.translation.z);
+        match sprite {
+            Some(mut sprite) if sprite.custom_size != Some(size) => {
+                sprite.custom_size = Some(size);
+            }
+            Some(_) => {}
+            None => transform.scale = size.extend(1.),
+        }
+    }
+}
+
 fn setup_cameras(mut commands: Commands, dimensions: Res<CanvasDimensions>) {
     commands.spawn((
-        Canvas,
+        MainCanvas,
+        CanvasId::MAIN,
         Transform::from_xyz(0., 0., -999.9).with_scale(Vec3::splat(dimensions.pixel_scale)),
         HIGH_RES_LAYER,
     ));
     commands.spawn((
         Camera2d,
         Camera {
-            hdr: true,
+            hdr: HDR,
             order: 0,
             clear_color: ClearColorConfig::Custom(Color::BLACK),
             ..Default::default()
         },
         MainCamera,
+        CanvasId::MAIN,
         Msaa::Off,
     ));
     commands.spawn((
         Camera2d,
         Camera {
-            hdr: true,
+            hdr: HDR,
             order: 1,
             ..Default::default()
         },
@@ -109,50 +420,160 @@ fn setup_cameras(mut commands: Commands, dimensions: Res<CanvasDimensions>) {
     ));
 }
 
+/// Fits the [`OuterCamera`] and every [`WindowCanvasCamera`] to the window that they render to.
+///
+/// Cameras are only refit when their own window changes size or scale factor.
 fn fit_canvas(
     dimensions: Res<CanvasDimensions>,
+    viewport: Option<Res<CanvasViewport>>,
     mut resize_events: EventReader<WindowResized>,
-    mut projection: Single<&mut Projection, With<OuterCamera>>,
+    mut scale_factor_events: EventReader<WindowScaleFactorChanged>,
+    windows: Query<&Window>,
+    primary_window: Option<Single<Entity, With<PrimaryWindow>>>,
+    mut outer_cameras: Query<
+        (&mut Camera, &mut Projection, Option<&WindowCanvasCamera>),
+        Or<(With<OuterCamera>, With<WindowCanvasCamera>)>,
+    >,
+    mut canvases: Query<
+        (&CanvasId, &mut Transform, Option<Ref<CanvasDimensions>>),
+        (
+            With<Canvas>,
+            Without<OuterCamera>,
+            Without<WindowCanvasCamera>,
+        ),
+    >,
 ) {
+    let mut resized = HashMap::<Entity, Option<Vec2>>::default();
     for event in resize_events.read() {
-        let h_scale = event.width / dimensions.width as f32;
-        let v_scale = event.height / dimensions.height as f32;
-        let scale = h_scale.min(v_scale) / dimensions.pixel_scale;
+        resized.insert(event.window, Some(Vec2::new(event.width, event.height)));
+    }
+    for event in scale_factor_events.read() {
+        resized.entry(event.window).or_insert(None);
+    }
+    let primary_window = primary_window.map(|window| *window);
+
+    for (mut camera, mut projection, window_canvas) in outer_cameras.iter_mut() {
+        let RenderTarget::Window(window_ref) = &camera.target else {
+            continue;
+        };
+        let Some(entity) = window_ref
+            .normalize(primary_window)
+            .map(|window| window.entity())
+        else {
+            continue;
+        };
+
+        // the viewport only applies to the primary window's outer camera
+        let viewport = window_canvas
+            .is_none()
+            .then_some(viewport.as_ref())
+            .flatten();
+        let viewport_changed = window_canvas.is_none()
+            && match viewport {
+                Some(viewport) => viewport.is_changed(),
+                None => camera.viewport.is_some(),
+            };
+
+        let size = match resized.get(&entity) {
+            Some(size) => *size,
+            None if camera.is_added() || viewport_changed => None,
+            None => continue,
+        };
+        let Some((mut size, scale_factor)) = (match windows.get(entity) {
+            Ok(window) => Some((window.size(), window.scale_factor())),
+            Err(_) => size.map(|size| (size, 1.)),
+        }) else {
+            continue;
+        };
 
-        if let Projection::Orthographic(projection) = projection.as_mut() {
-            projection.scale = 1. / scale;
+        match viewport {
+            Some(viewport) => {
+                let rect = viewport.physical_rect((size * scale_factor).round().as_uvec2());
+                camera.viewport = Some(Viewport {
+                    physical_position: rect.min,
+                    physical_size: rect.size().max(UVec2::ONE),
+                    ..Default::default()
+                });
+                size = rect.size().as_vec2() / scale_factor;
+            }
+            None if window_canvas.is_none() && camera.viewport.is_some() => {
+                camera.viewport = None;
+            }
+            None => {}
+        }
+
+        let canvas = window_canvas.map_or(CanvasId::MAIN, |window_canvas| window_canvas.canvas);
+        let canvas_dimensions = canvases
+            .iter()
+            .find_map(|(id, _, local)| (*id == canvas).then_some(local).flatten())
+            .map_or(*dimensions, |local| *local);
+
+        // Keep the last valid scale while minimized.
+        if let Some(scale) = canvas_dimensions.fit_scale(size, scale_factor) {
+            if let Projection::Orthographic(projection) = projection.as_mut() {
+                projection.scale = 1. / scale;
+            }
+        }
+    }
+
+    for (_, mut transform, local) in canvases.iter_mut() {
+        let (pixel_scale, changed) = match &local {
+            Some(local) => (local.pixel_scale, local.is_changed()),
+            None => (dimensions.pixel_scale, dimensions.is_changed()),
+        };
+
+        if changed {
+            transform.scale = Vec3::splat(pixel_scale);
         }
     }
 }
 
-fn resize_canvas(
-    mut commands: Commands,
+fn configure_projection(
     dimensions: Res<CanvasDimensions>,
-    mut images: ResMut<Assets<Image>>,
-    mut camera: Single<&mut Camera, With<MainCamera>>,
-    canvas: Single<Entity, With<Canvas>>,
+    config: Res<CanvasProjection>,
+    mut projection: Query<&mut Projection, With<MainCamera>>,
 ) {
-    if !dimensions.is_changed() {
+    let Some(mut projection) = single_or_warn(projection.single_mut(), "MainCamera") else {
         return;
-    }
+    };
+    let Projection::Orthographic(ortho) = projection.as_mut() else {
+        return;
+    };
 
-    let canvas_size = Extent3d {
-        width: dimensions.width,
-        height: dimensions.height,
-        ..default()
+    ortho.scaling_mode = ScalingMode::Fixed {
+        width: dimensions.width as f32,
+        height: dimensions.height as f32,
     };
+    ortho.near = config.near;
+    ortho.far = config.far;
+    ortho.viewport_origin = config.viewport_origin;
+}
 
+fn canvas_image(dimensions: &CanvasDimensions) -> Image {
+    let canvas_size = UVec2::new(dimensions.width, dimensions.height);
     info!("resizing pixel perfect canvas: {:?}", canvas_size);
-    let mut new_canvas = Image {
+    render_target_image(canvas_size)
+}
+
+/// A nearest sampled image that cameras can render into, and screenshots can be copied from.
+pub(crate) fn render_target_image(size: UVec2) -> Image {
+    let size = Extent3d {
+        width: size.x,
+        height: size.y,
+        ..default()
+    };
+
+    let mut image = Image {
         texture_descriptor: TextureDescriptor {
             label: None,
-            size: canvas_size,
+            size,
             dimension: TextureDimension::D2,
             format: TextureFormat::bevy_default(),
             mip_level_count: 1,
             sample_count: 1,
             usage: TextureUsages::TEXTURE_BINDING
                 | TextureUsages::COPY_DST
+                | TextureUsages::COPY_SRC
                 | TextureUsages::RENDER_ATTACHMENT,
             view_formats: &[],
         },
@@ -160,20 +581,150 @@ fn resize_canvas(
         ..default()
     };
 
-    new_canvas.resize(canvas_size);
-    let handle = images.add(new_canvas);
-    camera.target = RenderTarget::Image(handle.clone().into());
-    commands.entity(*canvas).insert(Sprite::from_image(handle));
+    image.resize(size);
+    image
 }
 
-fn propagate_render_layers(
+fn resize_canvas(
     mut commands: Commands,
-    parents: Query<(&Children, &RenderLayers), Or<(Changed<RenderLayers>, Changed<Children>)>>,
+    dimensions: Res<CanvasDimensions>,
+    mut images: ResMut<Assets<Image>>,
+    mut cameras: Query<(&mut Camera, &CanvasId)>,
+    canvases: Query<
+        (
+            Entity,
+            &CanvasId,
+            Option<Ref<CanvasDimensions>>,
+            Option<&Sprite>,
+        ),
+        With<Canvas>,
+    >,
 ) {
-    for (children, layers) in parents.iter() {
-        for child in children.iter() {
-            commands.entity(child).insert(layers.clone());
+    for (entity, id, local, sprite) in canvases.iter() {
+        let (size, changed) = match &local {
+            Some(local) => (**local, local.is_changed()),
+            None => (*dimensions, dimensions.is_changed()),
+        };
+
+        let handle = match sprite {
+            Some(sprite) if !changed => sprite.image.clone(),
+            _ => {
+                let handle = images.add(canvas_image(&size));
+                commands
+                    .entity(entity)
+                    .insert(Sprite::from_image(handle.clone()));
+                handle
+            }
+        };
+
+        // Also binds cameras spawned after their canvas.
+        for (mut camera, _) in cameras.iter_mut().filter(|(_, camera_id)| *camera_id == id) {
+            if !matches!(&camera.target, RenderTarget::Image(target) if target.handle == handle) {
+                camera.target = RenderTarget::Image(handle.clone().into());
+            }
+        }
+    }
+}
+
+/// Places an entity and all of its descendants, including those spawned later, on the
+/// [`Canvas`] or the high resolution layer.
+///
+/// Descendants with their own [`RenderLayers`] keep them, and descendants with their own
+/// assignment take it over for their subtree. Hierarchies without an assignment are untouched.
+///
+/// Third party spawners, such as particle systems, can be assigned automatically with
+/// [`LayerAssignmentAppExt::assign_layer`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component)]
+pub enum LayerAssignment {
+    /// Rendered by the [`MainCamera`] into the [`Canvas`].
+    #[default]
+    Canvas,
+    /// Rendered by the [`OuterCamera`] at the window's resolution.
+    HighRes,
+}
+
+impl LayerAssignment {
+    pub fn render_layers(&self) -> RenderLayers {
+        match self {
+            Self::Canvas => RenderLayers::default(),
+            Self::HighRes => HIGH_RES_LAYER,
+        }
+    }
+}
+
+pub trait LayerCommands {
+    /// Assigns `entity` and its descendants to the [`Canvas`] with [`LayerAssignment::Canvas`].
+    fn assign_to_canvas(&mut self, entity: Entity);
+
+    /// Assigns `entity` and its descendants to the high resolution layer with
+    /// [`LayerAssignment::HighRes`].
+    fn assign_to_high_res(&mut self, entity: Entity);
+}
+
+impl LayerCommands for Commands<'_, '_> {
+    fn assign_to_canvas(&mut self, entity: Entity) {
+        self.entity(entity).insert(LayerAssignment::Canvas);
+    }
+
+    fn assign_to_high_res(&mut self, entity: Entity) {
+        self.entity(entity).insert(LayerAssignment::HighRes);
+    }
+}
+
+pub trait LayerAssignmentAppExt {
+    /// Inserts `layer` into every entity that `T` is added to, e.g. a particle spawner.
+    fn assign_layer<T: Component>(&mut self, layer: LayerAssignment) -> &mut Self;
+}
+
+impl LayerAssignmentAppExt for App {
+    fn assign_layer<T: Component>(&mut self, layer: LayerAssignment) -> &mut Self {
+        self.add_observer(move |trigger: Trigger<OnAdd, T>, mut commands: Commands| {
+            commands.entity(trigger.target()).try_insert(layer);
+        })
+    }
+}
+
+/// Marks [`RenderLayers`] inherited from a [`LayerAssignment`], as opposed to layers set
+/// explicitly, which are left in place.
+#[derive(Component)]
+struct AssignedLayers;
+
+fn assign_layers(
+    mut commands: Commands,
+    changed: Query<(Entity, &LayerAssignment), Changed<LayerAssignment>>,
+    reparented: Query<Entity, Changed<ChildOf>>,
+    assignments: Query<&LayerAssignment>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    explicit: Query<(), (With<RenderLayers>, Without<AssignedLayers>)>,
+) {
+    for (entity, assignment) in changed.iter() {
+        commands
+            .entity(entity)
+            .try_insert(assignment.render_layers())
+            .try_remove::<AssignedLayers>();
+    }
+
+    // descendants follow their nearest assigned ancestor, so nested assignments are respected
+    let descendants = changed
+        .iter()
+        .flat_map(|(entity, _)| children.iter_descendants(entity));
+    let reparented = reparented
+        .iter()
+        .flat_map(|entity| std::iter::once(entity).chain(children.iter_descendants(entity)));
+    for entity in descendants.chain(reparented) {
+        if assignments.contains(entity) || explicit.contains(entity) {
+            continue;
         }
+        let Some(assignment) = parents
+            .iter_ancestors(entity)
+            .find_map(|ancestor| assignments.get(ancestor).ok())
+        else {
+            continue;
+        };
+        commands
+            .entity(entity)
+            .try_insert((assignment.render_layers(), AssignedLayers));
     }
 }
 
@@ -0,0 +1,61 @@
+use crate::calibration::DisplayCalibrationPlugin;
+use crate::camera::CameraAnimationPlugin;
+use crate::color_temperature::ColorTemperaturePlugin;
+use crate::colorblind::ColorblindFilterPlugin;
+use crate::damage::DamageVignettePlugin;
+use crate::debug::DebugPlugin;
+use crate::effect_target::EffectTarget;
+use crate::events::OptixEffectEventPlugin;
+use crate::flash::ScreenFlashPlugin;
+use crate::glitch::GlitchPlugin;
+use crate::hitstop::HitstopPlugin;
+use crate::intensity::PresentationIntensityPlugin;
+use crate::motion_blur::CameraMotionBlurPlugin;
+use crate::pixel_perfect::{CanvasDimensions, PixelPerfectPlugin};
+use crate::posterize::PosterizePlugin;
+use crate::refraction::RefractionPlugin;
+use crate::shake::ScreenShakePlugin;
+use crate::vhs::VhsPlugin;
+use crate::zorder::ZOrderPlugin;
+use bevy::app::PluginGroupBuilder;
+use bevy::prelude::*;
+
+/// The camera, pixel perfect, shake, zorder, hitstop, effect event, presentation intensity, and
+/// debug plugins, along with every built-in post process, rendering the
+/// [`MainCamera`](crate::camera::MainCamera) into a canvas with the given [`CanvasDimensions`].
+///
+/// Individual plugins are configured with [`PluginGroupBuilder::set`] and removed with
+/// [`PluginGroupBuilder::disable`]:
+///
+/// ```ignore
+/// app.add_plugins(
+///     OptixPlugins(CanvasDimensions::new(320, 180, 1.))
+///         .set(CameraAnimationPlugin::fixed())
+///         .disable::<VhsPlugin>(),
+/// );
+/// ```
+pub struct OptixPlugins(pub CanvasDimensions);
+
+impl PluginGroup for OptixPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(CameraAnimationPlugin::default())
+            .add(PixelPerfectPlugin(self.0))
+            .add(ScreenShakePlugin::default())
+            .add(ZOrderPlugin)
+            .add(HitstopPlugin)
+            .add(OptixEffectEventPlugin)
+            .add(PresentationIntensityPlugin)
+            .add(DebugPlugin)
+            .add(GlitchPlugin::default())
+            .add(PosterizePlugin)
+            .add(VhsPlugin)
+            .add(ColorTemperaturePlugin)
+            .add(ColorblindFilterPlugin)
+            .add(DamageVignettePlugin::default())
+            .add(ScreenFlashPlugin::default().with_target(EffectTarget::Canvas))
+            .add(CameraMotionBlurPlugin)
+            .add(RefractionPlugin)
+            .add(DisplayCalibrationPlugin)
+    }
+}
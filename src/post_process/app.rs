@@ -1,3 +1,32 @@
+use crate::camera::MainCamera;
+use bevy::{
+    asset::{load_internal_asset, weak_handle},
+    core_pipeline::{
+        blit::{BlitPipeline, BlitPipelineKey},
+        fullscreen_vertex_shader::fullscreen_shader_vertex_state,
+        prepass::{NormalPrepass, ViewPrepassTextures},
+    },
+    ecs::{component::Mutable, query::QueryItem},
+    image::BevyDefault,
+    render::{
+        Extract, ExtractSchedule, Render, RenderApp, RenderSet,
+        extract_component::{ExtractComponent, ExtractComponentPlugin},
+        extract_resource::ExtractResource,
+        render_graph::{
+            EmptyNode, NodeRunError, RenderGraph, RenderGraphApp, RenderGraphContext, RenderLabel,
+            ViewNode, ViewNodeRunner,
+        },
+        render_resource::*,
+        renderer::{RenderContext, RenderDevice, RenderQueue},
+        sync_component::SyncComponentPlugin,
+        sync_world::RenderEntity,
+        texture::{CachedTexture, FallbackImage, TextureCache},
+        view::{
+            ExtractedView, Msaa, ViewDepthTexture, ViewTarget, ViewUniform, ViewUniformOffset,
+            ViewUniforms,
+        },
+    },
+};
 use bevy::{
     core_pipeline::core_2d::graph::{Core2d, Node2d},
     prelude::*,
@@ -5,38 +34,149 @@ use bevy::{
         globals::{GlobalsBuffer, GlobalsUniform},
         render_resource::{
             ShaderType,
-            binding_types::{sampler, texture_2d, uniform_buffer},
+            binding_types::{
+                sampler, storage_buffer_read_only, texture_2d, texture_depth_2d,
+                texture_depth_2d_multisampled, uniform_buffer,
+            },
             encase::private::WriteInto,
         },
     },
 };
-use bevy::{
-    core_pipeline::fullscreen_vertex_shader::fullscreen_shader_vertex_state,
-    ecs::query::QueryItem,
-    render::{
-        RenderApp,
-        extract_component::{
-            ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
-            UniformComponentPlugin,
-        },
-        render_graph::{
-            NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner,
-        },
-        render_resource::*,
-        renderer::{RenderContext, RenderDevice},
-        view::ViewTarget,
-    },
-};
+use bevy_tween::{BevyTweenRegisterSystems, component_tween_system};
 use std::{fmt::Debug, hash::Hash, marker::PhantomData};
 
+use super::tween::TweenSettings;
+
+/// Shared helpers for post process shaders, imported with `#import bevy_optix::post_process`.
+///
+/// Declares the `view` uniform and provides aspect-corrected UVs, world to UV conversions, sRGB
+/// conversions, and hash/noise functions.
+pub const POST_PROCESS_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("3f0f41a4-2d1e-4b8e-93f3-5c4a6f1d2e77");
+
 pub trait PostProcessMaterial: ShaderType {
     /// Returns this material's fragment shader. If [`ShaderRef::Default`] is returned, the default mesh fragment shader
     /// will be used.
     fn fragment_shader() -> ShaderRef {
         ShaderRef::Default
     }
+
+    /// Uploads this material as a read-only storage buffer instead of a uniform buffer, for data
+    /// that exceeds uniform size limits.
+    ///
+    /// The `POST_PROCESS_STORAGE` shader def is set when the storage buffer is available. Storage
+    /// buffers are not supported on WebGL2, where the material falls back to a uniform buffer:
+    ///
+    /// ```wgsl
+    /// #ifdef POST_PROCESS_STORAGE
+    /// @group(0) @binding(2) var<storage> settings: Settings;
+    /// #else
+    /// @group(0) @binding(2) var<uniform> settings: Settings;
+    /// #endif
+    /// ```
+    fn storage_buffer() -> bool {
+        false
+    }
+
+    /// Renders this material at a fraction of the view's resolution, then upscales the result
+    /// into the view.
+    ///
+    /// Cuts the fragment cost of low frequency effects, such as blurs, on high resolution views.
+    fn resolution_scale() -> f32 {
+        1.
+    }
+
+    /// Binds the view's depth texture at binding 5 and sets the `DEPTH_TEXTURE` shader def.
+    ///
+    /// `MULTISAMPLED` is also set when the view uses MSAA, in which case the depth texture is a
+    /// `texture_depth_multisampled_2d`.
+    fn depth_texture() -> bool {
+        false
+    }
+
+    /// Binds the view's normal prepass texture at binding 6.
+    ///
+    /// The `NORMAL_PREPASS` shader def is set when the view has a [`NormalPrepass`], otherwise a
+    /// fallback texture is bound.
+    fn normal_prepass() -> bool {
+        false
+    }
+
+    /// Runs this material after every other post process, immediately before
+    /// [`Node2d::EndMainPassPostProcessing`].
+    ///
+    /// Intended for display calibration, which must see the final image. The order between
+    /// multiple final materials is unspecified.
+    fn final_pass() -> bool {
+        false
+    }
+
+    /// Extracts this material every frame, rather than only when the material changes.
+    ///
+    /// Required when the material's [`ExtractComponent`] derives its output from other
+    /// components, whose changes are not otherwise detected.
+    fn extract_every_frame() -> bool {
+        false
+    }
+}
+
+/// Enables or disables the post process `S` on a camera without removing `S`.
+///
+/// Disabled effects are skipped by the render graph node, avoiding the extraction churn and
+/// archetype moves caused by inserting and removing `S`.
+#[derive(Component)]
+pub struct PostProcessToggle<S> {
+    pub enabled: bool,
+    _phantom: PhantomData<fn() -> S>,
+}
+
+impl<S> PostProcessToggle<S> {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S> Default for PostProcessToggle<S> {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl<S> Clone for PostProcessToggle<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for PostProcessToggle<S> {}
+
+impl<S> Debug for PostProcessToggle<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(&format!(
+            "PostProcessToggle<{}>",
+            std::any::type_name::<S>()
+        ))
+        .field("enabled", &self.enabled)
+        .finish()
+    }
+}
+
+impl<S: 'static> ExtractComponent for PostProcessToggle<S> {
+    type QueryData = &'static Self;
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component(toggle: QueryItem<'_, Self::QueryData>) -> Option<Self::Out> {
+        Some(*toggle)
+    }
 }
 
+const DEPTH_BINDING: u32 = 5;
+const NORMAL_BINDING: u32 = 6;
+
 pub struct PostProcessPlugin<S>(PhantomData<S>);
 
 impl<S> Default for PostProcessPlugin<S> {
@@ -47,32 +187,89 @@ impl<S> Default for PostProcessPlugin<S> {
 
 impl<S> Plugin for PostProcessPlugin<S>
 where
-    S: Clone + Copy + Component + ExtractComponent + ShaderType + PostProcessMaterial + WriteInto,
+    S: Clone
+        + Copy
+        + Component<Mutability = Mutable>
+        + ExtractComponent<Out = S>
+        + ShaderType
+        + PostProcessMaterial
+        + WriteInto,
     ViewNodeRunner<PostProcessNode<S>>: FromWorld,
 {
     fn build(&self, app: &mut App) {
         app.add_plugins((
-            ExtractComponentPlugin::<S>::default(),
-            UniformComponentPlugin::<S>::default(),
-        ));
+            SyncComponentPlugin::<S>::default(),
+            ExtractComponentPlugin::<PostProcessToggle<S>>::default(),
+        ))
+        .add_tween_systems(component_tween_system::<TweenSettings<S>>());
+
+        if app.world().contains_resource::<Assets<Shader>>() {
+            load_internal_asset!(
+                app,
+                POST_PROCESS_SHADER_HANDLE,
+                "../shaders/post_process.wgsl",
+                Shader::from_wgsl
+            );
+        }
 
         let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
         };
 
         render_app
+            .add_systems(ExtractSchedule, extract_post_process::<S>)
+            .add_systems(
+                Render,
+                (
+                    prepare_post_process_pipelines::<S>.in_set(RenderSet::Prepare),
+                    prepare_post_process_buffers::<S>.in_set(RenderSet::PrepareResources),
+                    prepare_post_process_intermediate::<S>
+                        .in_set(RenderSet::PrepareResources)
+                        .run_if(|| S::resolution_scale() < 1.),
+                ),
+            )
             .add_render_graph_node::<ViewNodeRunner<PostProcessNode<S>>>(
                 Core2d,
                 PostProcessLabel::<S>::default(),
-            )
-            .add_render_graph_edges(
+            );
+
+        let has_final_pass = render_app
+            .world()
+            .get_resource::<RenderGraph>()
+            .and_then(|graph| graph.get_sub_graph(Core2d))
+            .is_some_and(|graph| graph.get_node_state(PostProcessFinalPass).is_ok());
+        if !has_final_pass {
+            render_app
+                .add_render_graph_node::<EmptyNode>(Core2d, PostProcessFinalPass)
+                .add_render_graph_edges(
+                    Core2d,
+                    (
+                        Node2d::Tonemapping,
+                        PostProcessFinalPass,
+                        Node2d::EndMainPassPostProcessing,
+                    ),
+                );
+        }
+
+        if S::final_pass() {
+            render_app.add_render_graph_edges(
                 Core2d,
                 (
-                    Node2d::Tonemapping,
+                    PostProcessFinalPass,
                     PostProcessLabel::<S>::default(),
                     Node2d::EndMainPassPostProcessing,
                 ),
             );
+        } else {
+            render_app.add_render_graph_edges(
+                Core2d,
+                (
+                    Node2d::Tonemapping,
+                    PostProcessLabel::<S>::default(),
+                    PostProcessFinalPass,
+                ),
+            );
+        }
     }
 
     fn finish(&self, app: &mut App) {
@@ -80,12 +277,139 @@ where
             return;
         };
 
-        render_app.init_resource::<PostProcessPipeline<S>>();
+        if !render_app.world().contains_resource::<RenderDevice>() {
+            return;
+        }
+
+        render_app
+            .init_resource::<PostProcessPipeline<S>>()
+            .init_resource::<SpecializedRenderPipelines<PostProcessPipeline<S>>>();
+    }
+}
+
+/// Applies a post process whose settings come from a [`Resource`] to every camera with `M`.
+///
+/// Intended for inherently global effects, such as color grading, where the settings should not
+/// live on a camera entity. The settings are extracted with [`ExtractResource`] and the effect is
+/// disabled while the source resource is absent.
+///
+/// Applies to the [`MainCamera`] by default. Use [`OuterCamera`] to also cover the high resolution
+/// layer, but not both, as the effect would be applied twice.
+///
+/// Adds the [`PostProcessPlugin`] for `S` if it is not already present.
+///
+/// [`OuterCamera`]: crate::pixel_perfect::OuterCamera
+pub struct GlobalPostProcessPlugin<S, M = MainCamera>(PhantomData<(S, M)>);
+
+impl<S, M> Default for GlobalPostProcessPlugin<S, M> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S, M> Plugin for GlobalPostProcessPlugin<S, M>
+where
+    S: Clone
+        + Copy
+        + Component<Mutability = Mutable>
+        + ExtractComponent<Out = S>
+        + ExtractResource
+        + ShaderType
+        + PostProcessMaterial
+        + WriteInto,
+    M: Component,
+    ViewNodeRunner<PostProcessNode<S>>: FromWorld,
+{
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<PostProcessPlugin<S>>() {
+            app.add_plugins(PostProcessPlugin::<S>::default());
+        }
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.add_systems(ExtractSchedule, extract_global_post_process::<S, M>);
     }
 }
 
+/// Extracts `S` for views that do not have it yet, or whose `S` changed.
+///
+/// Unchanged settings are left in place, so [`prepare_post_process_buffers`] does not upload
+/// them again.
+fn extract_post_process<S>(
+    mut commands: Commands,
+    settings: Extract<Query<(RenderEntity, Ref<S>, S::QueryData), S::QueryFilter>>,
+    extracted: Query<(), With<S>>,
+) where
+    S: Component + ExtractComponent<Out = S> + PostProcessMaterial,
+{
+    for (entity, source, query_item) in settings.iter() {
+        // render entities are respawned without their components when any synced component is
+        // removed, so missing settings are always extracted
+        if !S::extract_every_frame() && !source.is_changed() && extracted.contains(entity) {
+            continue;
+        }
+
+        match S::extract_component(query_item) {
+            Some(settings) => {
+                commands.entity(entity).insert(settings);
+            }
+            None => {
+                commands.entity(entity).remove::<S>();
+            }
+        }
+    }
+}
+
+fn extract_global_post_process<S, M>(
+    mut commands: Commands,
+    settings: Extract<Option<Res<S::Source>>>,
+    cameras: Extract<Query<RenderEntity, (With<Camera>, With<M>)>>,
+    extracted: Query<(), With<S>>,
+) where
+    S: Clone + Component + ExtractResource,
+    M: Component,
+{
+    let changed = settings
+        .as_ref()
+        .is_some_and(|settings| settings.is_changed());
+    let settings = settings.as_deref().map(S::extract_resource);
+    for entity in cameras.iter() {
+        match &settings {
+            Some(settings) if changed || !extracted.contains(entity) => {
+                commands.entity(entity).insert(settings.clone());
+            }
+            None if extracted.contains(entity) => {
+                commands.entity(entity).remove::<S>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The [`Core2d`] render graph label of the post process `S`.
+///
+/// Nodes for `S` run between [`Node2d::Tonemapping`] and [`PostProcessFinalPass`], or between
+/// [`PostProcessFinalPass`] and [`Node2d::EndMainPassPostProcessing`] for
+/// [`PostProcessMaterial::final_pass`] materials.
+/// Custom nodes can be ordered against a specific effect with this label, such as a bloom pass
+/// that runs after the glitch effect:
+///
+/// ```ignore
+/// render_app
+///     .add_render_graph_node::<ViewNodeRunner<BloomNode>>(Core2d, BloomLabel)
+///     .add_render_graph_edges(
+///         Core2d,
+///         (
+///             PostProcessLabel::<GlitchSettings>::default(),
+///             BloomLabel,
+///             Node2d::EndMainPassPostProcessing,
+///         ),
+///     );
+/// ```
 #[derive(Clone, RenderLabel)]
-struct PostProcessLabel<S>(PhantomData<S>);
+pub struct PostProcessLabel<S>(PhantomData<S>);
 
 impl<S> PartialEq for PostProcessLabel<S> {
     fn eq(&self, other: &Self) -> bool {
@@ -111,35 +435,58 @@ impl<S> Default for PostProcessLabel<S> {
     }
 }
 
+/// An empty [`Core2d`] node that runs after every post process, except for
+/// [`PostProcessMaterial::final_pass`] materials, which run after it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, RenderLabel)]
+pub struct PostProcessFinalPass;
+
 #[derive(Default)]
 struct PostProcessNode<S>(PhantomData<S>);
 
 impl<S> ViewNode for PostProcessNode<S>
 where
-    S: Clone + Copy + Component + ShaderType + WriteInto,
+    S: Clone + Copy + Component + PostProcessMaterial + WriteInto,
 {
     type ViewQuery = (
         &'static ViewTarget,
         &'static S,
-        &'static DynamicUniformIndex<S>,
+        &'static PostProcessBuffer<S>,
+        &'static ViewUniformOffset,
+        &'static PostProcessPipelineId<S>,
+        Option<&'static PostProcessIntermediate<S>>,
+        Option<&'static ViewDepthTexture>,
+        Option<&'static ViewPrepassTextures>,
+        Option<&'static PostProcessToggle<S>>,
     );
 
     fn run(
         &self,
         _graph: &mut RenderGraphContext,
         render_context: &mut RenderContext,
-        (view_target, _post_process_settings, settings_index): QueryItem<Self::ViewQuery>,
+        (
+            view_target,
+            _post_process_settings,
+            settings,
+            view_offset,
+            pipeline_id,
+            intermediate,
+            depth,
+            prepass,
+            toggle,
+        ): QueryItem<Self::ViewQuery>,
         world: &World,
     ) -> Result<(), NodeRunError> {
+        if toggle.is_some_and(|toggle| !toggle.enabled) {
+            return Ok(());
+        }
+
         let post_process_pipeline = world.resource::<PostProcessPipeline<S>>();
         let pipeline_cache = world.resource::<PipelineCache>();
-        let Some(pipeline) = pipeline_cache.get_render_pipeline(post_process_pipeline.pipeline_id)
-        else {
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id.id) else {
             return Ok(());
         };
 
-        let settings_uniforms = world.resource::<ComponentUniforms<S>>();
-        let Some(settings_binding) = settings_uniforms.uniforms().binding() else {
+        let Some(settings_binding) = settings.binding() else {
             return Ok(());
         };
 
@@ -147,33 +494,112 @@ where
             return Ok(());
         };
 
+        let Some(view_binding) = world.resource::<ViewUniforms>().uniforms.binding() else {
+            return Ok(());
+        };
+
+        let blit = match intermediate {
+            Some(intermediate) => {
+                let Some(blit) = pipeline_cache.get_render_pipeline(intermediate.blit_pipeline)
+                else {
+                    return Ok(());
+                };
+                Some((intermediate, blit))
+            }
+            None => None,
+        };
+
+        let depth = match (S::depth_texture(), depth) {
+            (true, Some(depth)) => Some(depth.view()),
+            (true, None) => return Ok(()),
+            (false, _) => None,
+        };
+
+        let normal = S::normal_prepass().then(|| {
+            prepass
+                .and_then(|prepass| prepass.normal_view())
+                .unwrap_or(&world.resource::<FallbackImage>().d2.texture_view)
+        });
+
         let post_process = view_target.post_process_write();
+        let mut entries = BindGroupEntries::sequential((
+            post_process.source,
+            &post_process_pipeline.sampler,
+            settings_binding,
+            globals_binding,
+            view_binding,
+        ))
+        .to_vec();
+        if let Some(depth) = depth {
+            entries.push(BindGroupEntry {
+                binding: DEPTH_BINDING,
+                resource: BindingResource::TextureView(depth),
+            });
+        }
+        if let Some(normal) = normal {
+            entries.push(BindGroupEntry {
+                binding: NORMAL_BINDING,
+                resource: BindingResource::TextureView(normal),
+            });
+        }
+
         let bind_group = render_context.render_device().create_bind_group(
             "post_process_bind_group",
-            &post_process_pipeline.layout,
-            &BindGroupEntries::sequential((
-                post_process.source,
-                &post_process_pipeline.sampler,
-                settings_binding,
-                globals_binding,
-            )),
+            if pipeline_id.multisampled {
+                &post_process_pipeline.layout_msaa
+            } else {
+                &post_process_pipeline.layout
+            },
+            &entries,
         );
 
-        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
-            label: Some("post_process_pass"),
-            color_attachments: &[Some(RenderPassColorAttachment {
-                view: post_process.destination,
-                resolve_target: None,
-                ops: Operations::default(),
-            })],
-            depth_stencil_attachment: None,
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
+        {
+            let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+                label: Some("post_process_pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: blit
+                        .map(|(intermediate, _)| &intermediate.texture.default_view)
+                        .unwrap_or(post_process.destination),
+                    resolve_target: None,
+                    ops: Operations::default(),
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_render_pipeline(pipeline);
+            render_pass.set_bind_group(0, &bind_group, &[view_offset.offset]);
+            render_pass.draw(0..3, 0..1);
+        }
+
+        if let Some((intermediate, blit)) = blit {
+            let blit_pipeline = world.resource::<BlitPipeline>();
+            let bind_group = render_context.render_device().create_bind_group(
+                "post_process_composite_bind_group",
+                &blit_pipeline.texture_bind_group,
+                &BindGroupEntries::sequential((
+                    &intermediate.texture.default_view,
+                    &blit_pipeline.sampler,
+                )),
+            );
 
-        render_pass.set_render_pipeline(pipeline);
-        render_pass.set_bind_group(0, &bind_group, &[settings_index.index()]);
-        render_pass.draw(0..3, 0..1);
+            let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+                label: Some("post_process_composite_pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: post_process.destination,
+                    resolve_target: None,
+                    ops: Operations::default(),
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_render_pipeline(blit);
+            render_pass.set_bind_group(0, &bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
 
         Ok(())
     }
@@ -182,8 +608,11 @@ where
 #[derive(Resource)]
 struct PostProcessPipeline<S> {
     layout: BindGroupLayout,
+    /// Identical to `layout` unless the material requests the depth texture.
+    layout_msaa: BindGroupLayout,
     sampler: Sampler,
-    pipeline_id: CachedRenderPipelineId,
+    shader: Handle<Shader>,
+    storage: bool,
     _phantom: PhantomData<S>,
 }
 
@@ -194,57 +623,294 @@ where
     fn from_world(world: &mut World) -> Self {
         let render_device = world.resource::<RenderDevice>();
 
-        let layout = render_device.create_bind_group_layout(
-            "glitch_bind_group_layout",
-            &BindGroupLayoutEntries::sequential(
+        let storage = S::storage_buffer() && storage_supported(render_device);
+        let settings = if storage {
+            storage_buffer_read_only::<S>(false)
+        } else {
+            uniform_buffer::<S>(false)
+        };
+
+        let entries = |multisampled: bool| {
+            let mut entries = BindGroupLayoutEntries::sequential(
                 ShaderStages::FRAGMENT,
                 (
                     texture_2d(TextureSampleType::Float { filterable: true }),
                     sampler(SamplerBindingType::Filtering),
-                    uniform_buffer::<S>(true),
+                    settings,
                     uniform_buffer::<GlobalsUniform>(false),
+                    uniform_buffer::<ViewUniform>(true),
                 ),
-            ),
-        );
+            )
+            .to_vec();
+
+            if S::depth_texture() {
+                let depth = if multisampled {
+                    texture_depth_2d_multisampled()
+                } else {
+                    texture_depth_2d()
+                };
+                entries.push(depth.build(DEPTH_BINDING, ShaderStages::FRAGMENT));
+            }
+
+            if S::normal_prepass() {
+                entries.push(
+                    texture_2d(TextureSampleType::Float { filterable: false })
+                        .build(NORMAL_BINDING, ShaderStages::FRAGMENT),
+                );
+            }
+
+            entries
+        };
 
+        let layout = render_device
+            .create_bind_group_layout("post_process_bind_group_layout", &entries(false));
+        let layout_msaa = render_device
+            .create_bind_group_layout("post_process_msaa_bind_group_layout", &entries(true));
+
+        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
         let shader = match S::fragment_shader() {
             ShaderRef::Handle(handle) => handle,
             ShaderRef::Path(path) => world.load_asset(path),
             ShaderRef::Default => todo!("default post_process shader"),
         };
 
-        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
-        let pipeline_id =
-            world
-                .resource_mut::<PipelineCache>()
-                .queue_render_pipeline(RenderPipelineDescriptor {
-                    label: Some(
-                        format!("post_process_{}_pipeline", std::any::type_name::<S>()).into(),
-                    ),
-                    layout: vec![layout.clone()],
-                    vertex: fullscreen_shader_vertex_state(),
-                    fragment: Some(FragmentState {
-                        shader,
-                        shader_defs: vec![],
-                        entry_point: "fragment".into(),
-                        targets: vec![Some(ColorTargetState {
-                            format: TextureFormat::Rgba16Float,
-                            blend: None,
-                            write_mask: ColorWrites::ALL,
-                        })],
-                    }),
-                    primitive: PrimitiveState::default(),
-                    depth_stencil: None,
-                    multisample: MultisampleState::default(),
-                    push_constant_ranges: vec![],
-                    zero_initialize_workgroup_memory: false,
-                });
-
         Self {
             layout,
+            layout_msaa,
             sampler,
-            pipeline_id,
+            shader,
+            storage,
             _phantom: PhantomData,
         }
     }
 }
+
+fn storage_supported(render_device: &RenderDevice) -> bool {
+    render_device.limits().max_storage_buffers_per_shader_stage > 0
+}
+
+/// Specializes a [`PostProcessPipeline`] for the view it renders to.
+///
+/// WebGL2 does not reliably support HDR targets, so the target format is derived from the view
+/// rather than assumed to be [`ViewTarget::TEXTURE_FORMAT_HDR`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct PostProcessPipelineKey {
+    hdr: bool,
+    /// Only set when the material requests the depth texture.
+    multisampled: bool,
+    normal_prepass: bool,
+}
+
+impl<S> SpecializedRenderPipeline for PostProcessPipeline<S>
+where
+    S: PostProcessMaterial,
+{
+    type Key = PostProcessPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        // `SIXTEEN_BYTE_ALIGNMENT` is provided by the pipeline cache when targeting WebGL2.
+        let mut shader_defs = Vec::new();
+        if self.storage {
+            shader_defs.push("POST_PROCESS_STORAGE".into());
+        }
+        if S::depth_texture() {
+            shader_defs.push("DEPTH_TEXTURE".into());
+        }
+        if key.multisampled {
+            shader_defs.push("MULTISAMPLED".into());
+        }
+        if key.normal_prepass {
+            shader_defs.push("NORMAL_PREPASS".into());
+        }
+
+        RenderPipelineDescriptor {
+            label: Some(format!("post_process_{}_pipeline", std::any::type_name::<S>()).into()),
+            layout: vec![if key.multisampled {
+                self.layout_msaa.clone()
+            } else {
+                self.layout.clone()
+            }],
+            vertex: fullscreen_shader_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: self.shader.clone(),
+                shader_defs,
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format: if key.hdr {
+                        ViewTarget::TEXTURE_FORMAT_HDR
+                    } else {
+                        TextureFormat::bevy_default()
+                    },
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: vec![],
+            zero_initialize_workgroup_memory: false,
+        }
+    }
+}
+
+#[derive(Component)]
+struct PostProcessPipelineId<S> {
+    id: CachedRenderPipelineId,
+    multisampled: bool,
+    _phantom: PhantomData<S>,
+}
+
+fn prepare_post_process_pipelines<S>(
+    mut commands: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<PostProcessPipeline<S>>>,
+    pipeline: Res<PostProcessPipeline<S>>,
+    views: Query<(Entity, &ExtractedView, &Msaa, Has<NormalPrepass>), With<S>>,
+) where
+    S: Component + PostProcessMaterial,
+{
+    for (entity, view, msaa, normal_prepass) in &views {
+        let key = PostProcessPipelineKey {
+            hdr: view.hdr,
+            multisampled: S::depth_texture() && msaa.samples() > 1,
+            normal_prepass: S::normal_prepass() && normal_prepass,
+        };
+        let id = pipelines.specialize(&pipeline_cache, &pipeline, key);
+
+        commands.entity(entity).insert(PostProcessPipelineId::<S> {
+            id,
+            multisampled: key.multisampled,
+            _phantom: PhantomData,
+        });
+    }
+}
+
+/// Per-view settings buffer, rewritten only when the view's settings change.
+///
+/// A storage buffer for materials that opt into [`PostProcessMaterial::storage_buffer`] where
+/// supported, otherwise a uniform buffer.
+#[derive(Component)]
+enum PostProcessBuffer<S: ShaderType> {
+    Storage(StorageBuffer<S>),
+    Uniform(UniformBuffer<S>),
+}
+
+impl<S: ShaderType + WriteInto> PostProcessBuffer<S> {
+    fn new(settings: S, storage: bool) -> Self {
+        if storage {
+            Self::Storage(StorageBuffer::from(settings))
+        } else {
+            Self::Uniform(UniformBuffer::from(settings))
+        }
+    }
+
+    fn write(&mut self, settings: S, render_device: &RenderDevice, render_queue: &RenderQueue) {
+        match self {
+            Self::Storage(buffer) => {
+                buffer.set(settings);
+                buffer.write_buffer(render_device, render_queue);
+            }
+            Self::Uniform(buffer) => {
+                buffer.set(settings);
+                buffer.write_buffer(render_device, render_queue);
+            }
+        }
+    }
+
+    fn binding(&self) -> Option<BindingResource> {
+        match self {
+            Self::Storage(buffer) => buffer.binding(),
+            Self::Uniform(buffer) => buffer.binding(),
+        }
+    }
+}
+
+fn prepare_post_process_buffers<S>(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    pipeline: Res<PostProcessPipeline<S>>,
+    mut views: Query<(Entity, Ref<S>, Option<&mut PostProcessBuffer<S>>)>,
+) where
+    S: Copy + Component + PostProcessMaterial + WriteInto,
+{
+    for (entity, settings, buffer) in views.iter_mut() {
+        match buffer {
+            Some(mut buffer) => {
+                if settings.is_changed() {
+                    buffer.write(*settings, &render_device, &render_queue);
+                }
+            }
+            None => {
+                let mut buffer = PostProcessBuffer::new(*settings, pipeline.storage);
+                buffer.write(*settings, &render_device, &render_queue);
+                commands.entity(entity).insert(buffer);
+            }
+        }
+    }
+}
+
+/// Reduced resolution target for materials with a [`PostProcessMaterial::resolution_scale`]
+/// below 1.
+#[derive(Component)]
+struct PostProcessIntermediate<S> {
+    texture: CachedTexture,
+    blit_pipeline: CachedRenderPipelineId,
+    _phantom: PhantomData<S>,
+}
+
+fn prepare_post_process_intermediate<S>(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    pipeline_cache: Res<PipelineCache>,
+    mut texture_cache: ResMut<TextureCache>,
+    blit_pipeline: Res<BlitPipeline>,
+    mut blit_pipelines: ResMut<SpecializedRenderPipelines<BlitPipeline>>,
+    views: Query<(Entity, &ViewTarget), With<S>>,
+) where
+    S: Component + PostProcessMaterial,
+{
+    let scale = S::resolution_scale().clamp(f32::EPSILON, 1.);
+
+    for (entity, view_target) in &views {
+        let size = view_target.main_texture().size();
+        let format = view_target.main_texture_format();
+
+        let texture = texture_cache.get(
+            &render_device,
+            TextureDescriptor {
+                label: Some("post_process_intermediate_texture"),
+                size: Extent3d {
+                    width: ((size.width as f32 * scale).ceil() as u32).max(1),
+                    height: ((size.height as f32 * scale).ceil() as u32).max(1),
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format,
+                usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            },
+        );
+
+        let blit_pipeline = blit_pipelines.specialize(
+            &pipeline_cache,
+            &blit_pipeline,
+            BlitPipelineKey {
+                texture_format: format,
+                blend_state: None,
+                samples: 1,
+            },
+        );
+
+        commands
+            .entity(entity)
+            .insert(PostProcessIntermediate::<S> {
+                texture,
+                blit_pipeline,
+                _phantom: PhantomData,
+            });
+    }
+}
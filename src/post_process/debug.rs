@@ -0,0 +1,222 @@
+use super::app::PostProcessToggle;
+use crate::pixel_perfect::HIGH_RES_LAYER;
+use bevy::ecs::component::Mutable;
+use bevy::prelude::*;
+use bevy::reflect::ReflectMut;
+use std::fmt::Write;
+
+/// Lists the post processes registered with [`PostProcessDebugAppExt::debug_post_process`] and
+/// tunes them at runtime.
+///
+/// Controls:
+/// - `F1`: show or hide the overlay.
+/// - `Tab`: select the next post process.
+/// - `Up`/`Down`: select a field.
+/// - `Left`/`Right`: scrub the selected numeric field, faster while holding `Shift`.
+/// - `Space`: enable or disable the selected post process with a [`PostProcessToggle`].
+pub struct PostProcessDebugPlugin;
+
+impl Plugin for PostProcessDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PostProcessDebug>()
+            .add_systems(Update, post_process_debug);
+    }
+}
+
+pub trait PostProcessDebugAppExt {
+    /// Adds `S` to the [`PostProcessDebugPlugin`] overlay.
+    fn debug_post_process<S>(&mut self) -> &mut Self
+    where
+        S: Component<Mutability = Mutable> + Reflect;
+}
+
+impl PostProcessDebugAppExt for App {
+    fn debug_post_process<S>(&mut self) -> &mut Self
+    where
+        S: Component<Mutability = Mutable> + Reflect,
+    {
+        self.world_mut()
+            .get_resource_or_init::<PostProcessDebug>()
+            .effects
+            .push(debug_effect::<S>);
+        self
+    }
+}
+
+/// State of the [`PostProcessDebugPlugin`] overlay.
+#[derive(Resource)]
+pub struct PostProcessDebug {
+    pub visible: bool,
+    /// The rate at which the selected field is scrubbed, per second.
+    pub scrub_rate: f32,
+    pub transform: Transform,
+    effects: Vec<DebugEffect>,
+    selected_effect: usize,
+    selected_field: usize,
+    text: Option<Entity>,
+}
+
+impl Default for PostProcessDebug {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            scrub_rate: 1.,
+            transform: Transform::default(),
+            effects: Vec::new(),
+            selected_effect: 0,
+            selected_field: 0,
+            text: None,
+        }
+    }
+}
+
+type DebugEffect = fn(&mut World, Option<&mut DebugInput>, &mut String);
+
+struct DebugInput {
+    field: usize,
+    toggle: bool,
+    scrub: f32,
+}
+
+fn debug_effect<S>(world: &mut World, input: Option<&mut DebugInput>, output: &mut String)
+where
+    S: Component<Mutability = Mutable> + Reflect,
+{
+    let name = std::any::type_name::<S>()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default();
+    let selected = input.is_some();
+
+    let Some(camera) = world
+        .query_filtered::<Entity, (With<S>, With<Camera>)>()
+        .iter(world)
+        .next()
+    else {
+        let _ = writeln!(
+            output,
+            "{} {name}: inactive",
+            if selected { ">" } else { " " }
+        );
+        return;
+    };
+
+    let mut entity = world.entity_mut(camera);
+    if input.as_ref().is_some_and(|input| input.toggle) {
+        let enabled = entity
+            .get::<PostProcessToggle<S>>()
+            .is_none_or(|toggle| toggle.enabled);
+        entity.insert(PostProcessToggle::<S>::new(!enabled));
+    }
+
+    let enabled = entity
+        .get::<PostProcessToggle<S>>()
+        .is_none_or(|toggle| toggle.enabled);
+    let _ = writeln!(
+        output,
+        "{} {name}: {}",
+        if selected { ">" } else { " " },
+        if enabled { "on" } else { "off" }
+    );
+
+    let mut settings = entity.get_mut::<S>().unwrap();
+    let ReflectMut::Struct(fields) = settings.reflect_mut() else {
+        return;
+    };
+
+    let mut input = input;
+    if let Some(input) = input.as_mut() {
+        input.field = input.field.min(fields.field_len().saturating_sub(1));
+    }
+
+    for i in 0..fields.field_len() {
+        let name = fields.name_at(i).unwrap_or_default().to_owned();
+        let field_selected = input.as_ref().is_some_and(|input| input.field == i);
+        let Some(field) = fields.field_at_mut(i) else {
+            continue;
+        };
+
+        if let Some(value) = field.try_downcast_mut::<f32>() {
+            if field_selected {
+                *value += input.as_ref().map_or(0., |input| input.scrub);
+            }
+            let _ = writeln!(
+                output,
+                "    {}{name}: {value:.3}",
+                if field_selected { "> " } else { "" }
+            );
+        } else {
+            let _ = writeln!(
+                output,
+                "    {}{name}: {field:?}",
+                if field_selected { "> " } else { "" }
+            );
+        }
+    }
+}
+
+fn post_process_debug(world: &mut World) {
+    let keys = world.resource::<ButtonInput<KeyCode>>();
+    let toggle_visible = keys.just_pressed(KeyCode::F1);
+    let next_effect = keys.just_pressed(KeyCode::Tab);
+    let previous_field = keys.just_pressed(KeyCode::ArrowUp);
+    let next_field = keys.just_pressed(KeyCode::ArrowDown);
+    let toggle = keys.just_pressed(KeyCode::Space);
+    let direction =
+        keys.pressed(KeyCode::ArrowRight) as i32 - keys.pressed(KeyCode::ArrowLeft) as i32;
+    let fast = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let dt = world.resource::<Time>().delta_secs();
+
+    let mut debug = world.resource_mut::<PostProcessDebug>();
+    if toggle_visible {
+        debug.visible = !debug.visible;
+    }
+
+    if !debug.visible || debug.effects.is_empty() {
+        if let Some(text) = debug.text.take() {
+            world.despawn(text);
+        }
+        return;
+    }
+
+    if next_effect {
+        debug.selected_effect = (debug.selected_effect + 1) % debug.effects.len();
+        debug.selected_field = 0;
+    }
+    if previous_field {
+        debug.selected_field = debug.selected_field.saturating_sub(1);
+    }
+    if next_field {
+        debug.selected_field += 1;
+    }
+
+    let mut input = DebugInput {
+        field: debug.selected_field,
+        toggle,
+        scrub: direction as f32 * debug.scrub_rate * if fast { 10. } else { 1. } * dt,
+    };
+    let effects = debug.effects.clone();
+    let selected = debug.selected_effect;
+
+    let mut output = String::new();
+    for (i, effect) in effects.into_iter().enumerate() {
+        effect(world, (i == selected).then_some(&mut input), &mut output);
+    }
+
+    let mut debug = world.resource_mut::<PostProcessDebug>();
+    debug.selected_field = input.field;
+    let transform = debug.transform;
+    let text = debug.text;
+
+    match text.filter(|text| world.get_entity(*text).is_ok()) {
+        Some(text) => {
+            world.entity_mut(text).insert(Text2d::new(output));
+        }
+        None => {
+            let text = world
+                .spawn((Text2d::new(output), HIGH_RES_LAYER, transform))
+                .id();
+            world.resource_mut::<PostProcessDebug>().text = Some(text);
+        }
+    }
+}
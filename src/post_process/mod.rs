@@ -5,10 +5,19 @@ use bevy::{
 use std::marker::PhantomData;
 
 mod app;
+mod debug;
+mod tween;
+
+use app::PostProcessToggle;
 
 pub mod prelude {
     pub use super::PostProcessCommand;
-    pub use super::app::{PostProcessMaterial, PostProcessPlugin};
+    pub use super::app::{
+        GlobalPostProcessPlugin, POST_PROCESS_SHADER_HANDLE, PostProcessFinalPass,
+        PostProcessLabel, PostProcessMaterial, PostProcessPlugin, PostProcessToggle,
+    };
+    pub use super::debug::{PostProcessDebug, PostProcessDebugAppExt, PostProcessDebugPlugin};
+    pub use super::tween::{TweenSettings, tween_settings};
 }
 
 /// Apply post processing to the main camera through an [`ApplyPostProcess`].
@@ -28,6 +37,10 @@ pub trait PostProcessCommand {
 
     /// Removes the post process from the camera with `M`.
     fn remove_post_process<T: ApplyPostProcess, M: Component>(&mut self);
+
+    /// Enables or disables the post process on the camera with `M` through a
+    /// [`PostProcessToggle`], leaving the post process itself in place.
+    fn toggle_post_process<T: Component, M: Component>(&mut self, enabled: bool);
 }
 
 impl PostProcessCommand for Commands<'_, '_> {
@@ -46,6 +59,10 @@ impl PostProcessCommand for Commands<'_, '_> {
     fn remove_post_process<T: ApplyPostProcess, M: Component>(&mut self) {
         self.queue(remove::<T, M>);
     }
+
+    fn toggle_post_process<T: Component, M: Component>(&mut self, enabled: bool) {
+        self.queue(toggle::<T, M>(enabled));
+    }
 }
 
 /// Determines how a post process is inserted and removed from the main camera.
@@ -107,6 +124,20 @@ pub fn bind<T: ApplyPostProcess + Sync, M: Component>(
     }
 }
 
+pub fn toggle<T: Component, M: Component>(enabled: bool) -> impl FnOnce(&mut World) -> Result {
+    move |world: &mut World| {
+        let camera = world.query_filtered::<Entity, With<M>>().single(world)?;
+        let mut camera = world.entity_mut(camera);
+        match camera.get_mut::<PostProcessToggle<T>>() {
+            Some(mut toggle) => toggle.enabled = enabled,
+            None => {
+                camera.insert(PostProcessToggle::<T>::new(enabled));
+            }
+        }
+        Ok(())
+    }
+}
+
 pub fn remove<T: ApplyPostProcess, M: Component>(world: &mut World) -> Result {
     let camera = world.query_filtered::<Entity, With<M>>().single(world)?;
     T::remove(&mut world.entity_mut(camera));
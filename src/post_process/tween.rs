@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use bevy_tween::prelude::Interpolator;
+
+/// Tweens a field of the post process settings `S` from `start` to `end`.
+///
+/// The field is selected with an accessor, so any numeric field of any settings can be animated:
+///
+/// ```ignore
+/// commands.animation().insert(tween(
+///     Duration::from_secs(1),
+///     EaseKind::QuadraticOut,
+///     camera.with(tween_settings::<VhsSettings>(0., 1., |s| &mut s.tracking)),
+/// ));
+/// ```
+///
+/// `f32` fields are registered for every [`PostProcessPlugin`](super::prelude::PostProcessPlugin).
+/// Other types must be registered with
+/// `app.add_tween_systems(component_tween_system::<TweenSettings<S, T>>())`.
+#[derive(Component)]
+pub struct TweenSettings<S, T = f32> {
+    start: T,
+    end: T,
+    field: fn(&mut S) -> &mut T,
+}
+
+impl<S, T> TweenSettings<S, T> {
+    pub fn new(start: T, end: T, field: fn(&mut S) -> &mut T) -> Self {
+        Self { start, end, field }
+    }
+}
+
+pub fn tween_settings<S, T>(start: T, end: T, field: fn(&mut S) -> &mut T) -> TweenSettings<S, T> {
+    TweenSettings::new(start, end, field)
+}
+
+impl<S, T> Interpolator for TweenSettings<S, T>
+where
+    S: Component,
+    T: StableInterpolate + Send + Sync + 'static,
+{
+    type Item = S;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        *(self.field)(item) = self.start.interpolate_stable(&self.end, value);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use bevy::asset::weak_handle;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_resource::ShaderRef;
+use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
+use serde::Deserialize;
+
+pub const POSTERIZE_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("7b35e0d9-2c4f-4e81-a6b3-f09d1e8c5a72");
+
+pub struct PosterizePlugin;
+
+impl Plugin for PosterizePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PosterizeSettings>()
+            .add_plugins(PostProcessPlugin::<PosterizeSettings>::default());
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
+
+        load_internal_asset!(
+            app,
+            POSTERIZE_SHADER_HANDLE,
+            "shaders/posterize.wgsl",
+            Shader::from_wgsl
+        );
+    }
+}
+
+/// Quantizes the screen's luminance into bands and darkens edges, for a comic or cel look.
+#[derive(Debug, Component, Clone, Copy, ExtractComponent, ShaderType, Reflect, Deserialize)]
+#[serde(default)]
+pub struct PosterizeSettings {
+    /// The number of luminance bands.
+    pub levels: f32,
+    /// How much edges are darkened, in the range `[0, 1]`.
+    pub edge_strength: f32,
+    // WebGL2 structs must be 16 byte aligned, wherever `SIXTEEN_BYTE_ALIGNMENT` is defined. The
+    // padding is public so that struct literals may end with `..default()` on every target.
+    #[cfg(all(feature = "webgl2", target_arch = "wasm32", not(feature = "webgpu")))]
+    #[doc(hidden)]
+    #[reflect(ignore)]
+    #[serde(skip)]
+    pub _webgl2_padding: Vec2,
+}
+
+impl Default for PosterizeSettings {
+    fn default() -> Self {
+        Self::new(4., 0.5)
+    }
+}
+
+impl PosterizeSettings {
+    pub fn new(levels: f32, edge_strength: f32) -> Self {
+        Self {
+            levels,
+            edge_strength,
+            #[cfg(all(feature = "webgl2", target_arch = "wasm32", not(feature = "webgpu")))]
+            _webgl2_padding: Vec2::ZERO,
+        }
+    }
+}
+
+impl PostProcessMaterial for PosterizeSettings {
+    fn fragment_shader() -> ShaderRef {
+        POSTERIZE_SHADER_HANDLE.into()
+    }
+}
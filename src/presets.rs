@@ -0,0 +1,137 @@
+//! Effect and shake presets loaded from `.optix.ron` files.
+//!
+//! ```ron
+//! (
+//!     shake: {
+//!         "explosion": (amplitude: 140., decay_per_second: 1.2),
+//!     },
+//!     glitch: {
+//!         "hit": (intensity: 0.8, shake_speed: 8.),
+//!     },
+//! )
+//! ```
+//!
+//! Omitted fields use their default values. Enable bevy's `file_watcher` feature to hot reload
+//! presets while the game is running.
+
+use crate::glitch::GlitchSettings;
+use crate::shake::ShakeSettings;
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub struct PresetPlugin;
+
+impl Plugin for PresetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<OptixPresets>()
+            .init_asset_loader::<OptixPresetsLoader>()
+            .init_resource::<PresetLibrary>()
+            .add_systems(PreUpdate, rebuild_preset_library);
+    }
+}
+
+/// A set of named presets loaded from a `.optix.ron` file.
+#[derive(Debug, Default, Clone, Asset, TypePath, Deserialize)]
+pub struct OptixPresets {
+    #[serde(default)]
+    pub shake: HashMap<String, ShakeSettings>,
+    #[serde(default)]
+    pub glitch: HashMap<String, GlitchSettings>,
+}
+
+/// The presets of every [`OptixPresets`] file added with [`PresetLibrary::add`].
+///
+/// Rebuilt whenever a file is loaded or modified. Later files override presets of the same name.
+#[derive(Debug, Default, Resource)]
+pub struct PresetLibrary {
+    handles: Vec<Handle<OptixPresets>>,
+    presets: OptixPresets,
+}
+
+impl PresetLibrary {
+    pub fn add(&mut self, presets: Handle<OptixPresets>) {
+        self.handles.push(presets);
+    }
+
+    pub fn shake(&self, name: &str) -> Option<&ShakeSettings> {
+        self.presets.shake.get(name)
+    }
+
+    pub fn glitch(&self, name: &str) -> Option<GlitchSettings> {
+        self.presets.glitch.get(name).copied()
+    }
+}
+
+fn rebuild_preset_library(
+    mut events: EventReader<AssetEvent<OptixPresets>>,
+    mut library: ResMut<PresetLibrary>,
+    assets: Res<Assets<OptixPresets>>,
+) {
+    let changed = events.read().any(|event| match event {
+        AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+            library.handles.iter().any(|handle| handle.id() == *id)
+        }
+        _ => false,
+    });
+
+    if !changed && !library.is_changed() {
+        return;
+    }
+
+    let mut presets = OptixPresets::default();
+    for file in library
+        .handles
+        .iter()
+        .filter_map(|handle| assets.get(handle))
+    {
+        presets.shake.extend(file.shake.clone());
+        presets.glitch.extend(file.glitch.clone());
+    }
+    library.bypass_change_detection().presets = presets;
+}
+
+#[derive(Default)]
+pub struct OptixPresetsLoader;
+
+#[derive(Debug)]
+pub enum OptixPresetsLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for OptixPresetsLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read presets: {err}"),
+            Self::Ron(err) => write!(f, "could not parse presets: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for OptixPresetsLoaderError {}
+
+impl AssetLoader for OptixPresetsLoader {
+    type Asset = OptixPresets;
+    type Settings = ();
+    type Error = OptixPresetsLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(OptixPresetsLoaderError::Io)?;
+        ron::de::from_bytes(&bytes).map_err(OptixPresetsLoaderError::Ron)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["optix.ron"]
+    }
+}
@@ -0,0 +1,103 @@
+use crate::accessibility::{ReducedMotion, motion_factor};
+use crate::bias::BiasOffset;
+use crate::camera::MainCamera;
+use crate::ease::OptixEase;
+use crate::replay::{OptixCommand, record_command};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Kicks the [`MainCamera`] away from its follow position, then eases it back.
+///
+/// Unlike [`Shake`](crate::shake::Shake), the kick has a single direction, which suits weapon
+/// recoil and heavy landings. The offset is applied through the [`BiasOffset`], so it composes
+/// with whatever is positioning the camera.
+#[derive(Debug, Clone, Copy, Component)]
+#[require(BiasOffset)]
+pub struct CameraRecoil {
+    kick: Vec2,
+    recovery: Duration,
+    elapsed: Duration,
+    pub easing: OptixEase,
+}
+
+impl Default for CameraRecoil {
+    fn default() -> Self {
+        Self {
+            kick: Vec2::ZERO,
+            recovery: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            easing: EaseFunction::CubicOut.into(),
+        }
+    }
+}
+
+impl CameraRecoil {
+    /// Kicks the camera by `offset` in world units, recovering over `recovery`.
+    ///
+    /// Kicks during a recovery are added to the remaining offset.
+    pub fn kick(&mut self, offset: Vec2, recovery: Duration) {
+        self.kick = self.offset() + offset;
+        self.recovery = recovery;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// The current offset from the follow position.
+    pub fn offset(&self) -> Vec2 {
+        if self.elapsed >= self.recovery {
+            return Vec2::ZERO;
+        }
+
+        let t = self.elapsed.as_secs_f32() / self.recovery.as_secs_f32();
+        self.kick * (1. - self.easing.sample(t))
+    }
+}
+
+pub trait RecoilCommands {
+    /// Kicks the [`MainCamera`] `strength` world units along `direction`, easing back to its
+    /// follow position over `recovery`.
+    fn camera_recoil(&mut self, direction: Vec2, strength: f32, recovery: Duration);
+}
+
+impl RecoilCommands for Commands<'_, '_> {
+    fn camera_recoil(&mut self, direction: Vec2, strength: f32, recovery: Duration) {
+        self.queue(move |world: &mut World| -> Result {
+            record_command(
+                world,
+                OptixCommand::Recoil {
+                    direction,
+                    strength,
+                    recovery,
+                },
+            );
+            let camera = world
+                .query_filtered::<Entity, With<MainCamera>>()
+                .single(world)?;
+            let motion = motion_factor(world.get_resource::<ReducedMotion>());
+            world
+                .entity_mut(camera)
+                .entry::<CameraRecoil>()
+                .or_default()
+                .into_mut()
+                .kick(direction.normalize_or_zero() * strength * motion, recovery);
+            Ok(())
+        });
+    }
+}
+
+pub(crate) fn camera_recoil(
+    camera: Option<Single<(&mut Transform, &mut BiasOffset, &mut CameraRecoil), With<MainCamera>>>,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut offset, mut recoil)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    if recoil.elapsed >= recoil.recovery {
+        return;
+    }
+
+    recoil.elapsed += time.delta();
+    let kick = recoil.offset();
+    transform.translation += kick.extend(0.);
+    offset.push(kick);
+}
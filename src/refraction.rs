@@ -0,0 +1,172 @@
+use crate::camera::MainCamera;
+use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use bevy::asset::weak_handle;
+use bevy::ecs::query::QueryItem;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_resource::ShaderRef;
+use bevy::transform::TransformSystem;
+use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
+
+pub const REFRACTION_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("5d2e9b84-0f6c-4a3d-8e71-c4b9a2f03e65");
+
+/// The most [`RefractionRegion`]s drawn at once.
+pub const MAX_REFRACTION_REGIONS: usize = 8;
+
+pub struct RefractionPlugin;
+
+impl Plugin for RefractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(PostProcessPlugin::<RefractionSettings>::default())
+            .add_systems(
+                PostUpdate,
+                gather_refraction_regions.after(TransformSystem::TransformPropagate),
+            );
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
+
+        load_internal_asset!(
+            app,
+            REFRACTION_SHADER_HANDLE,
+            "shaders/refraction.wgsl",
+            Shader::from_wgsl
+        );
+    }
+}
+
+/// A region of the world, centered on the entity, that wobbles and is tinted by the
+/// [`RefractionSettings`], e.g. ponds and heat zones.
+#[derive(Debug, Clone, Copy, Component)]
+#[require(Transform)]
+pub struct RefractionRegion {
+    pub size: Vec2,
+    /// Blended over the region by its alpha.
+    pub tint: Color,
+}
+
+impl RefractionRegion {
+    pub fn new(size: Vec2) -> Self {
+        Self {
+            size,
+            tint: Color::NONE,
+        }
+    }
+
+    pub fn with_tint(mut self, tint: impl Into<Color>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+}
+
+/// Draws the visible [`RefractionRegion`]s on the [`MainCamera`].
+#[derive(Debug, Component, Clone, Copy, ShaderType)]
+#[require(RefractionRegions)]
+pub struct RefractionSettings {
+    regions: [Vec4; MAX_REFRACTION_REGIONS],
+    tints: [Vec4; MAX_REFRACTION_REGIONS],
+    count: u32,
+    /// The largest displacement, in UVs.
+    pub strength: f32,
+    /// The scale of the wobble's noise.
+    pub frequency: f32,
+    /// How quickly the wobble changes.
+    pub speed: f32,
+}
+
+impl Default for RefractionSettings {
+    fn default() -> Self {
+        Self {
+            regions: [Vec4::ZERO; MAX_REFRACTION_REGIONS],
+            tints: [Vec4::ZERO; MAX_REFRACTION_REGIONS],
+            count: 0,
+            strength: 0.01,
+            frequency: 20.,
+            speed: 1.,
+        }
+    }
+}
+
+impl ExtractComponent for RefractionSettings {
+    type QueryData = (&'static Self, &'static RefractionRegions);
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component((settings, regions): QueryItem<'_, Self::QueryData>) -> Option<Self::Out> {
+        Some(Self {
+            regions: regions.regions,
+            tints: regions.tints,
+            count: regions.count,
+            ..*settings
+        })
+    }
+}
+
+impl PostProcessMaterial for RefractionSettings {
+    fn fragment_shader() -> ShaderRef {
+        REFRACTION_SHADER_HANDLE.into()
+    }
+
+    fn extract_every_frame() -> bool {
+        true
+    }
+}
+
+/// The [`RefractionRegion`]s visible to a camera, in UVs.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct RefractionRegions {
+    regions: [Vec4; MAX_REFRACTION_REGIONS],
+    tints: [Vec4; MAX_REFRACTION_REGIONS],
+    count: u32,
+}
+
+impl Default for RefractionRegions {
+    fn default() -> Self {
+        Self {
+            regions: [Vec4::ZERO; MAX_REFRACTION_REGIONS],
+            tints: [Vec4::ZERO; MAX_REFRACTION_REGIONS],
+            count: 0,
+        }
+    }
+}
+
+fn gather_refraction_regions(
+    mut cameras: Query<
+        (&mut RefractionRegions, &GlobalTransform, &Projection),
+        (With<MainCamera>, With<RefractionSettings>),
+    >,
+    regions: Query<(&RefractionRegion, &GlobalTransform)>,
+) {
+    for (mut gathered, camera, projection) in cameras.iter_mut() {
+        let Projection::Orthographic(ortho) = projection else {
+            continue;
+        };
+        let area = Rect {
+            min: ortho.area.min + camera.translation().xy(),
+            max: ortho.area.max + camera.translation().xy(),
+        };
+        if area.is_empty() {
+            continue;
+        }
+
+        let mut count = 0;
+        for (region, transform) in regions.iter() {
+            let rect = Rect::from_center_size(transform.translation().xy(), region.size);
+            if rect.intersect(area).is_empty() || count == MAX_REFRACTION_REGIONS {
+                continue;
+            }
+
+            // UVs point down
+            let to_uv = |p: Vec2| {
+                let uv = (p - area.min) / area.size();
+                Vec2::new(uv.x, 1. - uv.y)
+            };
+            let (min, max) = (to_uv(rect.min), to_uv(rect.max));
+            gathered.regions[count] = Vec4::new(min.x, max.y, max.x, min.y);
+            gathered.tints[count] = region.tint.to_linear().to_vec4();
+            count += 1;
+        }
+        gathered.count = count as u32;
+    }
+}
@@ -0,0 +1,285 @@
+use crate::camera::CameraMoveCommands;
+use crate::ease::OptixEase;
+use crate::flash::FlashCommands;
+use crate::glitch::GlitchCommands;
+use crate::hitstop::HitstopCommands;
+use crate::recoil::RecoilCommands;
+use crate::shake::TraumaCommands;
+use crate::zoom::ZoomCommands;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Records high-level optix commands into the [`OptixCommandLog`] and re-applies them while
+/// replaying.
+///
+/// Commands are timestamped with the elapsed [`Time`], so a replay driven by the same inputs
+/// reproduces the same camera presentation.
+///
+/// Only the [`OptixCommand`]s are recorded: the optix [`Commands`] extensions, and every
+/// [`AddTrauma`](crate::shake::AddTrauma) event however it is sent. [`MoveTo`] and [`ZoomTo`]
+/// components inserted directly, e.g. by timelines and impacts, are driven by their own inputs
+/// and are not recorded.
+///
+/// With the `serialize` feature, the log can be saved and loaded with `serde`.
+///
+/// [`MoveTo`]: crate::camera::MoveTo
+/// [`ZoomTo`]: crate::zoom::ZoomTo
+pub struct OptixReplayPlugin;
+
+impl Plugin for OptixReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OptixCommandLog>()
+            .add_systems(Update, replay_optix_commands);
+    }
+}
+
+/// A high-level command that the [`OptixCommandLog`] records.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum OptixCommand {
+    /// See [`TraumaCommands::add_trauma`].
+    Trauma(f32),
+    /// See [`GlitchCommands::glitch_burst`].
+    GlitchBurst { intensity: f32, duration: Duration },
+    /// See [`FlashCommands::flash_screen`].
+    Flash { color: Color, duration: Duration },
+    /// See [`FlashCommands::screen_transition`].
+    Transition { color: Color, duration: Duration },
+    /// See [`HitstopCommands::hitstop`].
+    Hitstop(Duration),
+    /// See [`CameraMoveCommands::move_camera_to`].
+    MoveTo {
+        translation: Vec2,
+        duration: Duration,
+        easing: OptixEase,
+    },
+    /// See [`CameraMoveCommands::cancel_camera_move`].
+    CancelMove { blend: Duration },
+    /// See [`ZoomCommands::zoom_to`].
+    ZoomTo {
+        scale: f32,
+        duration: Duration,
+        easing: OptixEase,
+    },
+    /// See [`ZoomCommands::move_and_zoom_to`].
+    MoveAndZoomTo {
+        translation: Vec2,
+        scale: f32,
+        duration: Duration,
+        easing: OptixEase,
+    },
+    /// See [`ZoomCommands::zoom_punch`].
+    ZoomPunch { strength: f32, duration: Duration },
+    /// See [`RecoilCommands::camera_recoil`].
+    Recoil {
+        direction: Vec2,
+        strength: f32,
+        recovery: Duration,
+    },
+}
+
+impl OptixCommand {
+    /// Issues the command through its [`Commands`] extension.
+    pub fn apply(self, commands: &mut Commands) {
+        match self {
+            Self::Trauma(trauma) => commands.add_trauma(trauma),
+            Self::GlitchBurst {
+                intensity,
+                duration,
+            } => commands.glitch_burst(intensity, duration),
+            Self::Flash { color, duration } => commands.flash_screen(color, duration),
+            Self::Transition { color, duration } => commands.screen_transition(color, duration),
+            Self::Hitstop(duration) => commands.hitstop(duration),
+            Self::MoveTo {
+                translation,
+                duration,
+                easing,
+            } => commands.move_camera_to(translation, duration, easing),
+            Self::CancelMove { blend } => commands.cancel_camera_move(blend),
+            Self::ZoomTo {
+                scale,
+                duration,
+                easing,
+            } => commands.zoom_to(scale, duration, easing),
+            Self::MoveAndZoomTo {
+                translation,
+                scale,
+                duration,
+                easing,
+            } => commands.move_and_zoom_to(translation, scale, duration, easing),
+            Self::ZoomPunch { strength, duration } => commands.zoom_punch(strength, duration),
+            Self::Recoil {
+                direction,
+                strength,
+                recovery,
+            } => commands.camera_recoil(direction, strength, recovery),
+        }
+    }
+}
+
+/// An [`OptixCommand`] issued `time` after the recording started.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoggedCommand {
+    pub time: Duration,
+    pub command: OptixCommand,
+}
+
+/// A timestamped log of [`OptixCommand`]s.
+///
+/// While recording, every [`OptixCommand`] is appended. While replaying, the
+/// [`OptixReplayPlugin`] re-applies each command once the same amount of time has passed since
+/// the replay started.
+///
+/// Only the entries are serialized, a deserialized log is idle.
+#[derive(Debug, Default, Clone, Resource)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptixCommandLog {
+    entries: Vec<LoggedCommand>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    state: LogState,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum LogState {
+    #[default]
+    Idle,
+    Recording {
+        start: Duration,
+    },
+    Replaying {
+        start: Duration,
+        next: usize,
+    },
+}
+
+impl OptixCommandLog {
+    pub fn entries(&self) -> &[LoggedCommand] {
+        &self.entries
+    }
+
+    /// Clears the log and begins recording at `now`.
+    pub fn start_recording(&mut self, now: Duration) {
+        self.entries.clear();
+        self.state = LogState::Recording { start: now };
+    }
+
+    /// Begins replaying the log from `now`.
+    pub fn start_replay(&mut self, now: Duration) {
+        self.state = LogState::Replaying {
+            start: now,
+            next: 0,
+        };
+    }
+
+    /// Stops recording or replaying, keeping the entries.
+    pub fn stop(&mut self) {
+        self.state = LogState::Idle;
+    }
+
+    pub fn recording(&self) -> bool {
+        matches!(self.state, LogState::Recording { .. })
+    }
+
+    pub fn replaying(&self) -> bool {
+        matches!(self.state, LogState::Replaying { .. })
+    }
+
+    /// Appends `command` if recording.
+    pub fn record(&mut self, now: Duration, command: OptixCommand) {
+        if let LogState::Recording { start } = self.state {
+            self.entries.push(LoggedCommand {
+                time: now.saturating_sub(start),
+                command,
+            });
+        }
+    }
+
+    /// Advances the replay to `now`, returning the commands that became due.
+    ///
+    /// The log stops once every command has been replayed.
+    pub fn advance_replay(&mut self, now: Duration) -> Vec<OptixCommand> {
+        let LogState::Replaying { start, next } = &mut self.state else {
+            return Vec::new();
+        };
+
+        let elapsed = now.saturating_sub(*start);
+        let due = self.entries[*next..]
+            .iter()
+            .take_while(|entry| entry.time <= elapsed)
+            .map(|entry| entry.command)
+            .collect::<Vec<_>>();
+        *next += due.len();
+
+        if *next >= self.entries.len() {
+            self.state = LogState::Idle;
+        }
+        due
+    }
+}
+
+pub trait OptixLogCommands {
+    /// Clears the [`OptixCommandLog`] and begins recording.
+    fn record_optix_commands(&mut self);
+
+    /// Replays the [`OptixCommandLog`] from the beginning.
+    fn replay_optix_commands(&mut self);
+
+    /// Stops recording or replaying the [`OptixCommandLog`].
+    fn stop_optix_log(&mut self);
+}
+
+impl OptixLogCommands for Commands<'_, '_> {
+    fn record_optix_commands(&mut self) {
+        self.queue(|world: &mut World| -> Result {
+            let now = world.resource::<Time>().elapsed();
+            log_mut(world)?.start_recording(now);
+            Ok(())
+        });
+    }
+
+    fn replay_optix_commands(&mut self) {
+        self.queue(|world: &mut World| -> Result {
+            let now = world.resource::<Time>().elapsed();
+            log_mut(world)?.start_replay(now);
+            Ok(())
+        });
+    }
+
+    fn stop_optix_log(&mut self) {
+        self.queue(|world: &mut World| -> Result {
+            log_mut(world)?.stop();
+            Ok(())
+        });
+    }
+}
+
+fn log_mut(world: &mut World) -> Result<Mut<'_, OptixCommandLog>> {
+    world
+        .get_resource_mut::<OptixCommandLog>()
+        .ok_or_else(|| "the command log does not exist, add the `OptixReplayPlugin`".into())
+}
+
+/// Appends `command` to the [`OptixCommandLog`] if it exists and is recording.
+pub(crate) fn record_command(world: &mut World, command: OptixCommand) {
+    let Some(now) = world.get_resource::<Time>().map(Time::elapsed) else {
+        return;
+    };
+    if let Some(mut log) = world.get_resource_mut::<OptixCommandLog>() {
+        log.record(now, command);
+    }
+}
+
+fn replay_optix_commands(
+    mut commands: Commands,
+    mut log: ResMut<OptixCommandLog>,
+    time: Res<Time>,
+) {
+    if !log.replaying() {
+        return;
+    }
+
+    for command in log.advance_replay(time.elapsed()) {
+        command.apply(&mut commands);
+    }
+}
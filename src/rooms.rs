@@ -0,0 +1,110 @@
+use crate::camera::{Binded, MainCamera, MoveTo};
+use crate::ease::OptixEase;
+use crate::visible::VisibleWorldRect;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// A region of the world that the [`MainCamera`] is confined to while the entity it is
+/// [`Binded`] to is inside of it.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct CameraRoom {
+    pub rect: Rect,
+}
+
+impl CameraRoom {
+    pub fn new(rect: Rect) -> Self {
+        Self { rect }
+    }
+}
+
+/// Confines the [`MainCamera`] to [`CameraRoom`]s, sliding between rooms as the entity it is
+/// [`Binded`] to crosses into them.
+///
+/// The slide is a [`MoveTo`] to the bound entity, after which the camera is bound to it again.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct RoomTransition {
+    pub duration: Duration,
+    pub easing: OptixEase,
+}
+
+impl Default for RoomTransition {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(500),
+            easing: EaseFunction::QuadraticInOut.into(),
+        }
+    }
+}
+
+impl RoomTransition {
+    pub fn new(duration: Duration, easing: impl Into<OptixEase>) -> Self {
+        Self {
+            duration,
+            easing: easing.into(),
+        }
+    }
+}
+
+/// The [`CameraRoom`] that the [`MainCamera`] is currently confined to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct CurrentRoom(pub Entity);
+
+pub(crate) fn enter_room(
+    mut commands: Commands,
+    camera: Option<
+        Single<(Entity, &RoomTransition, &Binded, Option<&CurrentRoom>), With<MainCamera>>,
+    >,
+    targets: Query<&Transform>,
+    rooms: Query<(Entity, &CameraRoom)>,
+    visible: VisibleWorldRect,
+) {
+    let Some((camera, transition, binded, current)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+    let target = binded.0;
+    let Ok(target_t) = targets.get(target) else {
+        return;
+    };
+
+    let Some((room, CameraRoom { rect })) = rooms
+        .iter()
+        .find(|(_, room)| room.rect.contains(target_t.translation.xy()))
+    else {
+        return;
+    };
+
+    if current.is_some_and(|current| current.0 == room) {
+        return;
+    }
+
+    let mut entity = commands.entity(camera);
+    entity.insert(CurrentRoom(room));
+
+    if let (Some(_), Some(region)) = (current, visible.camera_region(*rect)) {
+        entity.insert(
+            MoveTo::from_current_to_entity(target, transition.duration, transition.easing)
+                .clamped(region),
+        );
+    }
+}
+
+pub(crate) fn clamp_to_room(
+    camera: Option<Single<(&mut Transform, &CurrentRoom), (With<MainCamera>, Without<MoveTo>)>>,
+    rooms: Query<&CameraRoom>,
+    visible: VisibleWorldRect,
+) {
+    let Some((mut transform, current)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    let Some(region) = rooms
+        .get(current.0)
+        .ok()
+        .and_then(|room| visible.camera_region(room.rect))
+    else {
+        return;
+    };
+
+    let clamped = transform.translation.xy().clamp(region.min, region.max);
+    transform.translation = clamped.extend(transform.translation.z);
+}
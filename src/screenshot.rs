@@ -0,0 +1,203 @@
+//! Clean captures of the game, e.g. for marketing material.
+//!
+//! ```no_run
+//! # use bevy::prelude::*;
+//! # use bevy_optix::screenshot::*;
+//! fn capture(mut commands: Commands) {
+//!     commands.screenshot_mode(ScreenshotMode::new("capture.png").supersampled(2));
+//! }
+//! ```
+
+use crate::debug::{DebugCircle, DebugRect, DebugText};
+use crate::glitch::GlitchSettings;
+use crate::pixel_perfect::{CanvasBorder, OuterCamera, render_target_image};
+use crate::post_process::prelude::PostProcessToggle;
+use crate::shake::{Shake, ShakeSuppressed};
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::renderer::RenderDevice;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk};
+use bevy::window::PrimaryWindow;
+use std::path::PathBuf;
+
+/// Captures the [`OuterCamera`] with shake, glitch, canvas borders, and debug primitives
+/// disabled, restoring them once the screenshot is captured.
+#[derive(Debug, Clone)]
+pub struct ScreenshotMode {
+    pub path: PathBuf,
+    /// Renders at a multiple of the primary window's physical resolution.
+    pub supersample: Option<u32>,
+}
+
+impl ScreenshotMode {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            supersample: None,
+        }
+    }
+
+    pub fn supersampled(mut self, factor: u32) -> Self {
+        self.supersample = Some(factor);
+        self
+    }
+}
+
+pub trait ScreenshotCommands {
+    /// Takes a clean screenshot with [`ScreenshotMode`].
+    fn screenshot_mode(&mut self, mode: ScreenshotMode);
+}
+
+impl ScreenshotCommands for Commands<'_, '_> {
+    fn screenshot_mode(&mut self, mode: ScreenshotMode) {
+        self.queue(move |world: &mut World| -> Result {
+            let mut hidden = Hidden::hide(world);
+
+            let target = match mode.supersample.filter(|factor| *factor > 1) {
+                Some(factor) => match hidden.supersample(world, factor) {
+                    Ok(target) => target,
+                    Err(err) => {
+                        hidden.restore(world);
+                        return Err(err);
+                    }
+                },
+                None => Screenshot::primary_window(),
+            };
+
+            let mut hidden = Some(hidden);
+            world
+                .spawn(target)
+                .observe(save_to_disk(mode.path))
+                .observe(
+                    move |_: Trigger<ScreenshotCaptured>, mut commands: Commands| {
+                        if let Some(hidden) = hidden.take() {
+                            commands.queue(move |world: &mut World| hidden.restore(world));
+                        }
+                    },
+                );
+            Ok(())
+        });
+    }
+}
+
+/// The state disabled by a [`ScreenshotMode`].
+#[derive(Default)]
+struct Hidden {
+    shakes: Vec<Entity>,
+    glitches: Vec<(Entity, Option<PostProcessToggle<GlitchSettings>>)>,
+    visibility: Vec<(Entity, Visibility)>,
+    outer: Option<(Entity, RenderTarget, f32)>,
+}
+
+impl Hidden {
+    fn hide(world: &mut World) -> Self {
+        let shakes = world
+            .query_filtered::<Entity, (With<Shake>, Without<ShakeSuppressed>)>()
+            .iter(world)
+            .collect::<Vec<_>>();
+        for entity in shakes.iter() {
+            world.entity_mut(*entity).insert(ShakeSuppressed::new());
+        }
+
+        let glitches = world
+            .query_filtered::<(Entity, Option<&PostProcessToggle<GlitchSettings>>), With<GlitchSettings>>()
+            .iter(world)
+            .map(|(entity, toggle)| (entity, toggle.copied()))
+            .collect::<Vec<_>>();
+        for (entity, _) in glitches.iter() {
+            world
+                .entity_mut(*entity)
+                .insert(PostProcessToggle::<GlitchSettings>::new(false));
+        }
+
+        let visibility = world
+            .query_filtered::<(Entity, &Visibility), Or<(
+                With<CanvasBorder>,
+                With<DebugRect>,
+                With<DebugCircle>,
+                With<DebugText>,
+            )>>()
+            .iter(world)
+            .map(|(entity, visibility)| (entity, *visibility))
+            .collect::<Vec<_>>();
+        for (entity, _) in visibility.iter() {
+            world.entity_mut(*entity).insert(Visibility::Hidden);
+        }
+
+        Self {
+            shakes,
+            glitches,
+            visibility,
+            outer: None,
+        }
+    }
+
+    /// Renders the [`OuterCamera`] into an image `factor` times the size of the primary window,
+    /// lowering the factor to fit within the device's texture limits.
+    fn supersample(&mut self, world: &mut World, factor: u32) -> Result<Screenshot> {
+        let window = world
+            .query_filtered::<&Window, With<PrimaryWindow>>()
+            .single(world)?;
+        let max_dimension = world
+            .get_resource::<RenderDevice>()
+            .map_or(u32::MAX, |device| device.limits().max_texture_dimension_2d);
+        let factor = factor
+            .min(max_dimension / window.physical_size().max_element().max(1))
+            .max(1);
+        let size = window.physical_size() * factor;
+        // the image has a scale factor of 1
+        let scale = 1. / (factor as f32 * window.scale_factor());
+
+        world.resource_scope(|world, mut images: Mut<Assets<Image>>| {
+            let (entity, mut camera, mut projection) = world
+                .query_filtered::<(Entity, &mut Camera, &mut Projection), With<OuterCamera>>()
+                .single_mut(world)?;
+            let Projection::Orthographic(ortho) = projection.as_mut() else {
+                return Err("the outer camera's projection is not orthographic".into());
+            };
+
+            let handle = images.add(render_target_image(size));
+            self.outer = Some((entity, camera.target.clone(), ortho.scale));
+            camera.target = RenderTarget::Image(handle.clone().into());
+            ortho.scale *= scale;
+
+            Ok(Screenshot::image(handle))
+        })
+    }
+
+    fn restore(self, world: &mut World) {
+        for entity in self.shakes {
+            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                entity.remove::<ShakeSuppressed>();
+            }
+        }
+
+        for (entity, toggle) in self.glitches {
+            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                match toggle {
+                    Some(toggle) => entity.insert(toggle),
+                    None => entity.remove::<PostProcessToggle<GlitchSettings>>(),
+                };
+            }
+        }
+
+        for (entity, visibility) in self.visibility {
+            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                entity.insert(visibility);
+            }
+        }
+
+        if let Some((entity, target, scale)) = self.outer {
+            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                if let Some(mut camera) = entity.get_mut::<Camera>() {
+                    camera.target = target;
+                }
+                if let Some(Projection::Orthographic(ortho)) =
+                    entity.get_mut::<Projection>().as_deref_mut()
+                {
+                    ortho.scale = scale;
+                }
+            }
+        }
+    }
+}
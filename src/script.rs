@@ -0,0 +1,323 @@
+//! Parses camera and effect commands from text, for dialogue scripts, consoles, and mods.
+//!
+//! Enabled with the `script` feature.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_optix::script::ScriptCommands;
+//! fn on_line(mut commands: Commands) -> Result {
+//!     commands.optix("shake 0.4; flash #ffffff 0.2; zoom 0.8 1.5 cubic_in_out")?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Each command is a name followed by whitespace separated arguments. Durations are in seconds,
+//! colors are hex codes, `white`, or `black`, and easings are the snake case name of an
+//! [`EaseFunction`], e.g. `quadratic_out`:
+//!
+//! | Command | Arguments |
+//! | --- | --- |
+//! | `shake` | `trauma` |
+//! | `glitch` | `intensity duration` |
+//! | `flash` | `color duration` |
+//! | `transition` | `color duration` |
+//! | `punch` | `strength duration` |
+//! | `hitstop` | `duration` |
+//! | `move` | `x y duration [easing]` |
+//! | `zoom` | `scale duration [easing]` |
+//! | `move_zoom` | `x y scale duration [easing]` |
+//! | `cancel_move` | `[blend]` |
+//! | `recoil` | `x y strength recovery` |
+
+use crate::ease::OptixEase;
+use crate::events::OptixEffectEvent;
+use crate::replay::OptixCommand;
+use bevy::prelude::*;
+use std::iter::Peekable;
+use std::str::{FromStr, SplitWhitespace};
+use std::time::Duration;
+
+/// A parsed script command.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScriptCommand {
+    Effect(OptixEffectEvent),
+    Camera(OptixCommand),
+}
+
+impl ScriptCommand {
+    /// Issues the command through its [`Commands`] extension.
+    pub fn apply(self, commands: &mut Commands) {
+        match self {
+            Self::Effect(effect) => effect.apply(commands),
+            Self::Camera(command) => command.apply(commands),
+        }
+    }
+}
+
+impl FromStr for ScriptCommand {
+    type Err = ScriptError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let name = words.next().ok_or(ScriptError::Empty)?;
+        let mut args = Args::new(name, words);
+
+        let command = match name {
+            "shake" => Self::Effect(OptixEffectEvent::Trauma(args.f32("trauma")?)),
+            "glitch" => Self::Effect(OptixEffectEvent::GlitchBurst {
+                intensity: args.f32("intensity")?,
+                duration: args.duration("duration")?,
+            }),
+            "flash" => Self::Effect(OptixEffectEvent::Flash {
+                color: args.color("color")?,
+                duration: args.duration("duration")?,
+            }),
+            "transition" => Self::Effect(OptixEffectEvent::Transition {
+                color: args.color("color")?,
+                duration: args.duration("duration")?,
+            }),
+            "punch" => Self::Effect(OptixEffectEvent::ZoomPunch {
+                strength: args.f32("strength")?,
+                duration: args.duration("duration")?,
+            }),
+            "hitstop" => Self::Effect(OptixEffectEvent::Hitstop(args.duration("duration")?)),
+            "move" => Self::Camera(OptixCommand::MoveTo {
+                translation: args.vec2("x", "y")?,
+                duration: args.duration("duration")?,
+                easing: args.easing()?,
+            }),
+            "zoom" => Self::Camera(OptixCommand::ZoomTo {
+                scale: args.f32("scale")?,
+                duration: args.duration("duration")?,
+                easing: args.easing()?,
+            }),
+            "move_zoom" => Self::Camera(OptixCommand::MoveAndZoomTo {
+                translation: args.vec2("x", "y")?,
+                scale: args.f32("scale")?,
+                duration: args.duration("duration")?,
+                easing: args.easing()?,
+            }),
+            "cancel_move" => Self::Camera(OptixCommand::CancelMove {
+                blend: args.optional_duration("blend")?.unwrap_or_default(),
+            }),
+            "recoil" => Self::Camera(OptixCommand::Recoil {
+                direction: args.vec2("x", "y")?,
+                strength: args.f32("strength")?,
+                recovery: args.duration("recovery")?,
+            }),
+            _ => return Err(ScriptError::UnknownCommand(name.to_string())),
+        };
+
+        args.finish()?;
+        Ok(command)
+    }
+}
+
+/// Parses every command in `script`, separated by `;` or new lines.
+pub fn parse_script(script: &str) -> Result<Vec<ScriptCommand>, ScriptError> {
+    script
+        .split([';', '\n'])
+        .filter(|command| !command.trim().is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptError {
+    Empty,
+    UnknownCommand(String),
+    MissingArgument {
+        command: String,
+        argument: &'static str,
+    },
+    InvalidArgument {
+        command: String,
+        argument: &'static str,
+        value: String,
+    },
+    UnexpectedArgument {
+        command: String,
+        value: String,
+    },
+}
+
+impl std::fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty command"),
+            Self::UnknownCommand(command) => write!(f, "unknown command `{command}`"),
+            Self::MissingArgument { command, argument } => {
+                write!(f, "`{command}` is missing `{argument}`")
+            }
+            Self::InvalidArgument {
+                command,
+                argument,
+                value,
+            } => write!(f, "`{value}` is not a valid `{argument}` for `{command}`"),
+            Self::UnexpectedArgument { command, value } => {
+                write!(f, "unexpected argument `{value}` for `{command}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+pub trait ScriptCommands {
+    /// Parses `script` with [`parse_script`] and applies every command.
+    ///
+    /// Nothing is applied if any command fails to parse.
+    fn optix(&mut self, script: &str) -> Result<(), ScriptError>;
+}
+
+impl ScriptCommands for Commands<'_, '_> {
+    fn optix(&mut self, script: &str) -> Result<(), ScriptError> {
+        for command in parse_script(script)? {
+            command.apply(self);
+        }
+        Ok(())
+    }
+}
+
+pub(crate) struct Args<'a> {
+    command: &'a str,
+    words: Peekable<SplitWhitespace<'a>>,
+}
+
+impl<'a> Args<'a> {
+    pub(crate) fn new(command: &'a str, words: SplitWhitespace<'a>) -> Self {
+        Self {
+            command,
+            words: words.peekable(),
+        }
+    }
+
+    pub(crate) fn next(&mut self, argument: &'static str) -> Result<&'a str, ScriptError> {
+        self.words
+            .next()
+            .ok_or_else(|| ScriptError::MissingArgument {
+                command: self.command.to_string(),
+                argument,
+            })
+    }
+
+    pub(crate) fn invalid(&self, argument: &'static str, value: &str) -> ScriptError {
+        ScriptError::InvalidArgument {
+            command: self.command.to_string(),
+            argument,
+            value: value.to_string(),
+        }
+    }
+
+    pub(crate) fn f32(&mut self, argument: &'static str) -> Result<f32, ScriptError> {
+        let value = self.next(argument)?;
+        value
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .ok_or_else(|| self.invalid(argument, value))
+    }
+
+    pub(crate) fn vec2(&mut self, x: &'static str, y: &'static str) -> Result<Vec2, ScriptError> {
+        Ok(Vec2::new(self.f32(x)?, self.f32(y)?))
+    }
+
+    pub(crate) fn duration(&mut self, argument: &'static str) -> Result<Duration, ScriptError> {
+        let value = self.next(argument)?;
+        parse_duration(value).ok_or_else(|| self.invalid(argument, value))
+    }
+
+    pub(crate) fn optional_duration(
+        &mut self,
+        argument: &'static str,
+    ) -> Result<Option<Duration>, ScriptError> {
+        // Leave an easing in place so that `cam.move 10 20 cubic_out` skips the duration.
+        if self
+            .words
+            .peek()
+            .is_some_and(|value| parse_ease_function(value).is_some())
+        {
+            return Ok(None);
+        }
+        self.words
+            .next()
+            .map(|value| parse_duration(value).ok_or_else(|| self.invalid(argument, value)))
+            .transpose()
+    }
+
+    pub(crate) fn color(&mut self, argument: &'static str) -> Result<Color, ScriptError> {
+        let value = self.next(argument)?;
+        let color = match value {
+            "white" => Some(Color::WHITE),
+            "black" => Some(Color::BLACK),
+            hex => Srgba::hex(hex).ok().map(Color::from),
+        };
+        color.ok_or_else(|| self.invalid(argument, value))
+    }
+
+    /// An optional easing, linear if omitted.
+    pub(crate) fn easing(&mut self) -> Result<OptixEase, ScriptError> {
+        let Some(value) = self.words.next() else {
+            return Ok(OptixEase::default());
+        };
+        parse_ease_function(value)
+            .map(OptixEase::from)
+            .ok_or_else(|| self.invalid("easing", value))
+    }
+
+    pub(crate) fn finish(mut self) -> Result<(), ScriptError> {
+        match self.words.next() {
+            Some(value) => Err(ScriptError::UnexpectedArgument {
+                command: self.command.to_string(),
+                value: value.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_duration(value: &str) -> Option<Duration> {
+    value
+        .parse::<f32>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f32(seconds).ok())
+}
+
+fn parse_ease_function(value: &str) -> Option<EaseFunction> {
+    Some(match value {
+        "linear" => EaseFunction::Linear,
+        "quadratic_in" => EaseFunction::QuadraticIn,
+        "quadratic_out" => EaseFunction::QuadraticOut,
+        "quadratic_in_out" => EaseFunction::QuadraticInOut,
+        "cubic_in" => EaseFunction::CubicIn,
+        "cubic_out" => EaseFunction::CubicOut,
+        "cubic_in_out" => EaseFunction::CubicInOut,
+        "quartic_in" => EaseFunction::QuarticIn,
+        "quartic_out" => EaseFunction::QuarticOut,
+        "quartic_in_out" => EaseFunction::QuarticInOut,
+        "quintic_in" => EaseFunction::QuinticIn,
+        "quintic_out" => EaseFunction::QuinticOut,
+        "quintic_in_out" => EaseFunction::QuinticInOut,
+        "smoothstep" => EaseFunction::SmoothStep,
+        "smootherstep" => EaseFunction::SmootherStep,
+        "sine_in" => EaseFunction::SineIn,
+        "sine_out" => EaseFunction::SineOut,
+        "sine_in_out" => EaseFunction::SineInOut,
+        "circular_in" => EaseFunction::CircularIn,
+        "circular_out" => EaseFunction::CircularOut,
+        "circular_in_out" => EaseFunction::CircularInOut,
+        "exponential_in" => EaseFunction::ExponentialIn,
+        "exponential_out" => EaseFunction::ExponentialOut,
+        "exponential_in_out" => EaseFunction::ExponentialInOut,
+        "elastic_in" => EaseFunction::ElasticIn,
+        "elastic_out" => EaseFunction::ElasticOut,
+        "elastic_in_out" => EaseFunction::ElasticInOut,
+        "back_in" => EaseFunction::BackIn,
+        "back_out" => EaseFunction::BackOut,
+        "back_in_out" => EaseFunction::BackInOut,
+        "bounce_in" => EaseFunction::BounceIn,
+        "bounce_out" => EaseFunction::BounceOut,
+        "bounce_in_out" => EaseFunction::BounceInOut,
+        _ => return None,
+    })
+}
@@ -0,0 +1,26 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_optix::post_process::{luminance, linear_to_srgb, srgb_to_linear}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+struct Settings {
+    gamma: f32,
+    brightness: f32,
+    contrast: f32,
+    saturation: f32,
+};
+@group(0) @binding(2) var<uniform> settings: Settings;
+
+@fragment
+fn fragment(mesh: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen = textureSample(screen_texture, screen_sampler, mesh.uv);
+
+    // Calibrate in display space, where the settings are perceptually even
+    var color = linear_to_srgb(max(screen.rgb, vec3<f32>(0.0)));
+    color += settings.brightness;
+    color = (color - 0.5) * settings.contrast + 0.5;
+    color = mix(vec3<f32>(luminance(color)), color, settings.saturation);
+    color = pow(max(color, vec3<f32>(0.0)), vec3<f32>(1.0 / max(settings.gamma, 0.0001)));
+
+    return vec4<f32>(srgb_to_linear(color), screen.a);
+}
@@ -0,0 +1,28 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+struct Settings {
+    temperature: f32,
+    tint: f32,
+    exposure: f32,
+#ifdef SIXTEEN_BYTE_ALIGNMENT
+    _webgl2_padding: f32,
+#endif
+};
+@group(0) @binding(2) var<uniform> settings: Settings;
+
+@fragment
+fn fragment(mesh: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen = textureSample(screen_texture, screen_sampler, mesh.uv);
+
+    // Warm shifts toward red and away from blue, tint shifts between green and magenta
+    let balance = vec3<f32>(
+        1.0 + 0.2 * settings.temperature,
+        1.0 - 0.2 * settings.tint,
+        1.0 - 0.2 * settings.temperature,
+    );
+    let color = screen.rgb * max(balance, vec3<f32>(0.0)) * exp2(settings.exposure);
+
+    return vec4<f32>(color, screen.a);
+}
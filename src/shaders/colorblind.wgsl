@@ -0,0 +1,65 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+struct Settings {
+    deficiency: u32,
+    mode: u32,
+    strength: f32,
+#ifdef SIXTEEN_BYTE_ALIGNMENT
+    _webgl2_padding: f32,
+#endif
+};
+@group(0) @binding(2) var<uniform> settings: Settings;
+
+const PROTANOPIA: u32 = 0u;
+const DEUTERANOPIA: u32 = 1u;
+const SIMULATE: u32 = 1u;
+
+// Machado et al. 2009 simulation matrices for linear RGB at full severity.
+//
+// The rows are passed as columns so that `color * matrix` applies them.
+fn simulation(deficiency: u32) -> mat3x3<f32> {
+    if deficiency == PROTANOPIA {
+        return mat3x3<f32>(
+            vec3<f32>(0.152286, 1.052583, -0.204868),
+            vec3<f32>(0.114503, 0.786281, 0.099216),
+            vec3<f32>(-0.003882, -0.048116, 1.051998),
+        );
+    }
+    if deficiency == DEUTERANOPIA {
+        return mat3x3<f32>(
+            vec3<f32>(0.367322, 0.860646, -0.227968),
+            vec3<f32>(0.280085, 0.672501, 0.047413),
+            vec3<f32>(-0.011820, 0.042940, 0.968881),
+        );
+    }
+    return mat3x3<f32>(
+        vec3<f32>(1.255528, -0.076749, -0.178779),
+        vec3<f32>(-0.078411, 0.930809, 0.147602),
+        vec3<f32>(0.004733, 0.691367, 0.303900),
+    );
+}
+
+// Redistributes the color lost to the deficiency into the channels that remain visible.
+fn shift_error(deficiency: u32, error: vec3<f32>) -> vec3<f32> {
+    if deficiency == PROTANOPIA || deficiency == DEUTERANOPIA {
+        return vec3<f32>(0.0, error.r * 0.7 + error.g, error.r * 0.7 + error.b);
+    }
+    return vec3<f32>(error.r + error.b * 0.7, error.g + error.b * 0.7, 0.0);
+}
+
+@fragment
+fn fragment(mesh: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen = textureSample(screen_texture, screen_sampler, mesh.uv);
+
+    let simulated = screen.rgb * simulation(settings.deficiency);
+    var filtered = simulated;
+    if settings.mode != SIMULATE {
+        let error = screen.rgb - simulated;
+        filtered = max(screen.rgb + shift_error(settings.deficiency, error), vec3<f32>(0.0));
+    }
+
+    let color = mix(screen.rgb, filtered, clamp(settings.strength, 0.0, 1.0));
+    return vec4<f32>(color, screen.a);
+}
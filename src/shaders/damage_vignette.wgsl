@@ -0,0 +1,25 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_optix::post_process::aspect_uv
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+struct Settings {
+    color: vec4<f32>,
+    intensity: f32,
+    radius: f32,
+    softness: f32,
+};
+@group(0) @binding(2) var<uniform> settings: Settings;
+
+@fragment
+fn fragment(mesh: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen = textureSample(screen_texture, screen_sampler, mesh.uv);
+
+    // Grows inward from the corners as the intensity increases
+    let distance = length(aspect_uv(mesh.uv));
+    let radius = settings.radius * (1.0 - settings.intensity);
+    let vignette = smoothstep(radius, radius + settings.softness, distance) * settings.intensity;
+
+    let color = mix(screen.rgb, settings.color.rgb, vignette * settings.color.a);
+    return vec4<f32>(color, screen.a);
+}
@@ -10,6 +10,9 @@ struct Settings {
     shake_block_size: f32,
     shake_color_rate: f32,
     intensity: f32,
+#ifdef SIXTEEN_BYTE_ALIGNMENT
+    _webgl2_padding: vec2<f32>,
+#endif
 };
 @group(0) @binding(2) var<uniform> settings: Settings;
 @group(0) @binding(3) var<uniform> globals: Globals;
@@ -0,0 +1,30 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+struct Settings {
+    direction: vec2<f32>,
+    strength: f32,
+    max_length: f32,
+};
+@group(0) @binding(2) var<uniform> settings: Settings;
+
+const SAMPLES: i32 = 8;
+
+@fragment
+fn fragment(mesh: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    var blur = settings.direction * settings.strength;
+    let blur_length = length(blur);
+    if blur_length > settings.max_length {
+        blur *= settings.max_length / blur_length;
+    }
+
+    // Samples centered on the pixel, trailing opposite to the camera's motion
+    var color = vec4<f32>(0.0);
+    for (var i = 0; i < SAMPLES; i++) {
+        let t = f32(i) / f32(SAMPLES - 1) - 0.5;
+        color += textureSample(screen_texture, screen_sampler, mesh.uv - blur * t);
+    }
+
+    return color / f32(SAMPLES);
+}
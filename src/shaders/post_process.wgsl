@@ -0,0 +1,72 @@
+#define_import_path bevy_optix::post_process
+
+#import bevy_render::view::View
+
+@group(0) @binding(4) var<uniform> view: View;
+
+// UVs centered on the screen and scaled so that one unit has the same length on both axes.
+fn aspect_uv(uv: vec2<f32>) -> vec2<f32> {
+    let size = view.viewport.zw;
+    return (uv - 0.5) * vec2<f32>(size.x / size.y, 1.0);
+}
+
+fn world_to_uv(world: vec3<f32>) -> vec2<f32> {
+    let clip = view.clip_from_world * vec4<f32>(world, 1.0);
+    let ndc = clip.xy / clip.w;
+    return ndc * vec2<f32>(0.5, -0.5) + 0.5;
+}
+
+fn uv_to_world(uv: vec2<f32>) -> vec3<f32> {
+    let ndc = (uv - 0.5) * vec2<f32>(2.0, -2.0);
+    let world = view.world_from_clip * vec4<f32>(ndc, 0.0, 1.0);
+    return world.xyz / world.w;
+}
+
+fn srgb_to_linear(color: vec3<f32>) -> vec3<f32> {
+    let low = color / 12.92;
+    let high = pow((color + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, color <= vec3<f32>(0.04045));
+}
+
+fn linear_to_srgb(color: vec3<f32>) -> vec3<f32> {
+    let low = color * 12.92;
+    let high = 1.055 * pow(color, vec3<f32>(1.0 / 2.4)) - 0.055;
+    return select(high, low, color <= vec3<f32>(0.0031308));
+}
+
+fn luminance(color: vec3<f32>) -> f32 {
+    return dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+}
+
+fn hash11(p: f32) -> f32 {
+    var x = fract(p * 0.1031);
+    x *= x + 33.33;
+    x *= x + x;
+    return fract(x);
+}
+
+fn hash12(p: vec2<f32>) -> f32 {
+    var p3 = fract(vec3<f32>(p.xyx) * 0.1031);
+    p3 += dot(p3, p3.yzx + 33.33);
+    return fract((p3.x + p3.y) * p3.z);
+}
+
+fn hash22(p: vec2<f32>) -> vec2<f32> {
+    var p3 = fract(vec3<f32>(p.xyx) * vec3<f32>(0.1031, 0.1030, 0.0973));
+    p3 += dot(p3, p3.yzx + 33.33);
+    return fract((p3.xx + p3.yz) * p3.zy);
+}
+
+// Smooth value noise in the range [0, 1].
+fn value_noise(p: vec2<f32>) -> f32 {
+    let i = floor(p);
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+
+    let a = hash12(i);
+    let b = hash12(i + vec2<f32>(1.0, 0.0));
+    let c = hash12(i + vec2<f32>(0.0, 1.0));
+    let d = hash12(i + vec2<f32>(1.0, 1.0));
+
+    return mix(mix(a, b, u.x), mix(c, d, u.x), u.y);
+}
@@ -0,0 +1,45 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_optix::post_process::luminance
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+struct Settings {
+    levels: f32,
+    edge_strength: f32,
+#ifdef SIXTEEN_BYTE_ALIGNMENT
+    _webgl2_padding: vec2<f32>,
+#endif
+};
+@group(0) @binding(2) var<uniform> settings: Settings;
+
+fn sample_luminance(uv: vec2<f32>) -> f32 {
+    return luminance(textureSample(screen_texture, screen_sampler, uv).rgb);
+}
+
+@fragment
+fn fragment(mesh: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen = textureSample(screen_texture, screen_sampler, mesh.uv);
+
+    // Quantize the luminance, preserving hue
+    let levels = max(settings.levels, 1.0);
+    let lum = luminance(screen.rgb);
+    let stepped = floor(lum * levels + 0.5) / levels;
+    var color = screen.rgb * (stepped / max(lum, 0.0001));
+
+    // Sobel edges on the luminance
+    let texel = 1.0 / vec2<f32>(textureDimensions(screen_texture));
+    let tl = sample_luminance(mesh.uv + texel * vec2<f32>(-1.0, -1.0));
+    let t = sample_luminance(mesh.uv + texel * vec2<f32>(0.0, -1.0));
+    let tr = sample_luminance(mesh.uv + texel * vec2<f32>(1.0, -1.0));
+    let l = sample_luminance(mesh.uv + texel * vec2<f32>(-1.0, 0.0));
+    let r = sample_luminance(mesh.uv + texel * vec2<f32>(1.0, 0.0));
+    let bl = sample_luminance(mesh.uv + texel * vec2<f32>(-1.0, 1.0));
+    let b = sample_luminance(mesh.uv + texel * vec2<f32>(0.0, 1.0));
+    let br = sample_luminance(mesh.uv + texel * vec2<f32>(1.0, 1.0));
+    let gx = -tl - 2.0 * l - bl + tr + 2.0 * r + br;
+    let gy = -tl - 2.0 * t - tr + bl + 2.0 * b + br;
+    let edge = clamp(length(vec2<f32>(gx, gy)), 0.0, 1.0);
+
+    color *= 1.0 - edge * settings.edge_strength;
+    return vec4<f32>(color, screen.a);
+}
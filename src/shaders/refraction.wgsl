@@ -0,0 +1,40 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_optix::post_process::value_noise
+
+const MAX_REGIONS: u32 = 8u;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+struct Settings {
+    regions: array<vec4<f32>, MAX_REGIONS>,
+    tints: array<vec4<f32>, MAX_REGIONS>,
+    count: u32,
+    strength: f32,
+    frequency: f32,
+    speed: f32,
+};
+@group(0) @binding(2) var<uniform> settings: Settings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+
+@fragment
+fn fragment(mesh: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    var uv = mesh.uv;
+    var tint = vec4<f32>(0.0);
+
+    for (var i = 0u; i < min(settings.count, MAX_REGIONS); i++) {
+        let region = settings.regions[i];
+        if all(mesh.uv >= region.xy) && all(mesh.uv <= region.zw) {
+            let p = mesh.uv * settings.frequency + globals.time * settings.speed;
+            let offset = vec2<f32>(
+                value_noise(p),
+                value_noise(p + vec2<f32>(17.0, 31.0)),
+            ) - 0.5;
+            uv += offset * settings.strength;
+            tint = settings.tints[i];
+        }
+    }
+
+    let screen = textureSample(screen_texture, screen_sampler, uv);
+    return vec4<f32>(mix(screen.rgb, tint.rgb, tint.a), screen.a);
+}
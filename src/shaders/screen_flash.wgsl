@@ -0,0 +1,16 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+struct Settings {
+    color: vec4<f32>,
+    intensity: f32,
+};
+@group(0) @binding(2) var<uniform> settings: Settings;
+
+@fragment
+fn fragment(mesh: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen = textureSample(screen_texture, screen_sampler, mesh.uv);
+    let color = mix(screen.rgb, settings.color.rgb, settings.intensity * settings.color.a);
+    return vec4<f32>(color, screen.a);
+}
@@ -0,0 +1,44 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_optix::post_process::{hash12, value_noise}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+struct Settings {
+    tracking: f32,
+    wobble: f32,
+    chroma_bleed: f32,
+    head_switch: f32,
+};
+@group(0) @binding(2) var<uniform> settings: Settings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+
+@fragment
+fn fragment(mesh: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let time = globals.time;
+    var uv = mesh.uv;
+
+    // Tape wobble, a slow horizontal drift that varies down the screen
+    uv.x += (value_noise(vec2<f32>(uv.y * 4.0, time * 2.0)) - 0.5) * 0.01 * settings.wobble;
+
+    // Tracking noise, bands that roll up the screen and tear the picture sideways
+    let band = value_noise(vec2<f32>(uv.y * 8.0 - time * 1.5, time));
+    let tracking = smoothstep(1.0 - 0.3 * settings.tracking, 1.0, band) * settings.tracking;
+    uv.x += (hash12(vec2<f32>(floor(uv.y * 240.0), time)) - 0.5) * 0.05 * tracking;
+
+    // Head switching, a torn bar along the bottom of the screen
+    let head = step(1.0 - settings.head_switch, uv.y);
+    uv.x += head * (hash12(vec2<f32>(floor(uv.y * 120.0), floor(time * 30.0))) - 0.5) * 0.08;
+
+    // Chroma bleeds to the right of luma
+    let bleed = vec2<f32>(0.004 * settings.chroma_bleed, 0.0);
+    let color = vec3<f32>(
+        textureSample(screen_texture, screen_sampler, uv + bleed).r,
+        textureSample(screen_texture, screen_sampler, uv).g,
+        textureSample(screen_texture, screen_sampler, uv + bleed * 2.0).b,
+    );
+    let alpha = textureSample(screen_texture, screen_sampler, uv).a;
+
+    let noise = hash12(mesh.uv * 512.0 + time) * tracking * 0.5;
+    return vec4<f32>(color + noise, alpha);
+}
@@ -22,27 +22,110 @@
 //!
 //! Simple camera shake API with configurable [`ShakeSettings`] on a camera.
 
+use crate::accessibility::{ReducedMotion, motion_factor};
+use crate::bounds::CameraBounds;
+use crate::effect_target::{EffectTarget, EffectTargetCommands};
+use crate::pixel_perfect::CanvasDimensions;
+use crate::replay::{OptixCommand, OptixCommandLog};
+use crate::visible::VisibleWorldRect;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
 
 pub mod prelude {
-    pub use super::{ScreenShakePlugin, Shake, ShakeSettings, TraumaCommands};
+    pub use super::{
+        AddTrauma, ScreenShakePlugin, Shake, ShakeNoise, ShakeSettings, ShakeSuppressed,
+        ShakeSystem, TraumaCommands,
+    };
+}
+
+/// Shakes entities with a [`Shake`].
+///
+/// By default, the shake is applied in [`PostUpdate`] and restored in [`PreUpdate`]. Systems that
+/// need the unshaken translation outside of [`Update`] can order themselves against the
+/// [`ShakeSystem`] sets, or move the schedules.
+pub struct ScreenShakePlugin {
+    /// The schedule in which [`ShakeSystem::Restore`] runs.
+    pub restore_schedule: InternedScheduleLabel,
+    /// The schedule in which [`ShakeSystem::ApplyTrauma`] and [`ShakeSystem::Shake`] run.
+    pub shake_schedule: InternedScheduleLabel,
+    /// Inserts a [`Shake`] on the targeted camera at startup.
+    ///
+    /// [`EffectTarget::Canvas`] shakes only the canvas, leaving the high resolution layer still.
+    pub target: Option<EffectTarget>,
+}
+
+impl Default for ScreenShakePlugin {
+    fn default() -> Self {
+        Self {
+            restore_schedule: PreUpdate.intern(),
+            shake_schedule: PostUpdate.intern(),
+            target: None,
+        }
+    }
 }
 
-pub struct ScreenShakePlugin;
+impl ScreenShakePlugin {
+    pub fn with_restore_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.restore_schedule = schedule.intern();
+        self
+    }
+
+    pub fn with_shake_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.shake_schedule = schedule.intern();
+        self
+    }
+
+    pub fn with_target(mut self, target: EffectTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
 
 impl Plugin for ScreenShakePlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.register_type::<Shake>()
             .register_type::<ShakeSettings>()
-            .add_systems(PreUpdate, restore)
+            .add_event::<AddTrauma>()
+            .add_systems(self.restore_schedule, restore.in_set(ShakeSystem::Restore))
             .add_systems(
-                PostUpdate,
-                shake.before(TransformSystem::TransformPropagate),
+                self.shake_schedule,
+                (
+                    apply_trauma.in_set(ShakeSystem::ApplyTrauma),
+                    (tick_suppression, shake).chain().in_set(ShakeSystem::Shake),
+                    crate::shake_recording::record_shake.after(ShakeSystem::Shake),
+                ),
+            )
+            .configure_sets(
+                self.shake_schedule,
+                (ShakeSystem::ApplyTrauma, ShakeSystem::Shake)
+                    .chain()
+                    .before(TransformSystem::TransformPropagate),
             );
+
+        if let Some(target) = self.target {
+            app.add_systems(PostStartup, move |mut commands: Commands| {
+                commands.insert_effect(target, Shake::default());
+            });
+        }
     }
 }
 
-#[derive(Component, Reflect, Clone, Debug)]
+/// The systems of the [`ScreenShakePlugin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum ShakeSystem {
+    /// Restores the unshaken translation.
+    Restore,
+    /// Applies [`AddTrauma`] events.
+    ApplyTrauma,
+    /// Offsets the translation of every [`Shake`].
+    Shake,
+}
+
+#[derive(Component, Reflect, Clone, Debug, Deserialize)]
+#[serde(default)]
 pub struct ShakeSettings {
     /// the amplitude of the shake, how far it can offset
     pub amplitude: f32,
@@ -54,6 +137,18 @@ pub struct ShakeSettings {
     pub frequency: f32,
     /// how many layers of noise (detail if you will)
     pub octaves: usize,
+    /// where the shake offsets are sampled from
+    #[reflect(ignore)]
+    #[serde(skip)]
+    pub noise: ShakeNoise,
+    /// offsets along the entity's rotated axes instead of the world axes
+    pub local_space: bool,
+    /// scales the amplitude by the orthographic projection's scale, keeping the shake's size on
+    /// screen consistent at every zoom level
+    pub scale_with_zoom: bool,
+    /// measures the amplitude in scaled canvas pixels, dividing it by
+    /// [`CanvasDimensions::pixel_scale`] so the shake looks the same at every canvas resolution
+    pub canvas_pixels: bool,
 }
 
 impl Default for ShakeSettings {
@@ -69,14 +164,127 @@ impl ShakeSettings {
         amplitude: 100.,
         frequency: 15.,
         octaves: 1,
+        noise: ShakeNoise::Simplex,
+        local_space: false,
+        scale_with_zoom: false,
+        canvas_pixels: false,
     };
 }
 
+/// The source of a [`Shake`]'s offsets, giving the shake its character.
+///
+/// Every source is sampled at [`ShakeSettings::frequency`] times the elapsed seconds and returns
+/// offsets in the range `[-1, 1]`.
+#[derive(Default, Clone)]
+pub enum ShakeNoise {
+    /// Smooth simplex fBm, layered with [`ShakeSettings::octaves`].
+    #[default]
+    Simplex,
+    /// Perlin gradient fBm, layered with [`ShakeSettings::octaves`].
+    Perlin,
+    /// A new random offset at every whole sample, held until the next.
+    SampleAndHold,
+    /// A user provided curve, repeated over its domain when bounded.
+    Curve(Arc<dyn Curve<Vec2> + Send + Sync>),
+}
+
+impl std::fmt::Debug for ShakeNoise {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Simplex => f.write_str("Simplex"),
+            Self::Perlin => f.write_str("Perlin"),
+            Self::SampleAndHold => f.write_str("SampleAndHold"),
+            Self::Curve(_) => f.write_str("Curve"),
+        }
+    }
+}
+
+impl ShakeNoise {
+    pub fn curve(curve: impl Curve<Vec2> + Send + Sync + 'static) -> Self {
+        Self::Curve(Arc::new(curve))
+    }
+
+    fn sample(&self, t: f32, octaves: usize) -> Vec2 {
+        let lacunarity = 2.;
+        let gain = 0.5;
+
+        match self {
+            Self::Simplex => {
+                let noise_pos = vec2(t, 0.);
+                Vec2::new(
+                    noise::fbm_simplex_2d(noise_pos + vec2(0., 1.), octaves, lacunarity, gain),
+                    noise::fbm_simplex_2d(noise_pos + vec2(0., 2.), octaves, lacunarity, gain),
+                )
+            }
+            Self::Perlin => Vec2::new(
+                fbm_perlin_1d(t, 1, octaves, lacunarity, gain),
+                fbm_perlin_1d(t, 2, octaves, lacunarity, gain),
+            ),
+            Self::SampleAndHold => {
+                let step = t.floor() as i32 as u32;
+                Vec2::new(hash(step, 1), hash(step, 2))
+            }
+            Self::Curve(curve) => {
+                let domain = curve.domain();
+                let t = if domain.is_bounded() && domain.length() > 0. {
+                    domain.start() + (t - domain.start()).rem_euclid(domain.length())
+                } else {
+                    t
+                };
+                curve.sample_clamped(t)
+            }
+        }
+    }
+}
+
+/// Hashes `n` into the range `[-1, 1]`.
+fn hash(n: u32, seed: u32) -> f32 {
+    let mut x = n.wrapping_mul(0x27d4_eb2d) ^ seed.wrapping_mul(0x1656_67b1);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x2c1b_3c6d);
+    x ^= x >> 12;
+    x = x.wrapping_mul(0x297a_2d39);
+    x ^= x >> 15;
+    x as f32 / u32::MAX as f32 * 2. - 1.
+}
+
+fn perlin_1d(x: f32, seed: u32) -> f32 {
+    let cell = x.floor();
+    let f = x - cell;
+    let i = cell as i32 as u32;
+
+    let g0 = hash(i, seed) * f;
+    let g1 = hash(i.wrapping_add(1), seed) * (f - 1.);
+    let fade = f * f * f * (f * (f * 6. - 15.) + 10.);
+
+    // gradients in [-1, 1] peak at 0.5
+    2. * (g0 + (g1 - g0) * fade)
+}
+
+fn fbm_perlin_1d(x: f32, seed: u32, octaves: usize, lacunarity: f32, gain: f32) -> f32 {
+    let mut sum = 0.;
+    let mut amplitude = 1.;
+    let mut total = 0.;
+    let mut frequency = 1.;
+
+    for octave in 0..octaves.max(1) {
+        sum += amplitude * perlin_1d(x * frequency, seed.wrapping_add(octave as u32 * 31));
+        total += amplitude;
+        amplitude *= gain;
+        frequency *= lacunarity;
+    }
+
+    sum / total
+}
+
 /// Makes the entity shake according to applied trauma.
 ///
 /// The shake happens during [`PostUpdate`], and the entity is restored to its
 /// original translation in [`PreUpdate`]. This means that you can still control
-/// the camera like you normally would inside update.
+/// the camera like you normally would inside update. The schedules are configured
+/// by the [`ScreenShakePlugin`].
+///
+/// Cameras with [`CameraBounds`] never shake outside of their bounds.
 #[derive(Component, Reflect, Default, Clone, Debug)]
 pub struct Shake {
     trauma: f32,
@@ -99,6 +307,11 @@ impl Shake {
         self.trauma = (self.trauma + amount).clamp(0., 1.);
     }
 
+    /// The current trauma, in the range `[0, 1]`.
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
     pub fn pause(&mut self) {
         self.paused = true;
     }
@@ -106,11 +319,63 @@ impl Shake {
     pub fn unpause(&mut self) {
         self.paused = false;
     }
+
+    /// The unshaken translation while the shake is applied, i.e. between
+    /// [`ShakeSystem::Shake`] and [`ShakeSystem::Restore`].
+    pub fn reference_translation(&self) -> Option<Vec3> {
+        self.reference_translation
+    }
+}
+
+/// Prevents a [`Shake`] from offsetting the entity, e.g. while precisely aiming or in dialogue.
+///
+/// Trauma is held rather than decayed, and the shake resumes once this is removed.
+#[derive(Component, Default, Clone, Debug)]
+pub struct ShakeSuppressed(Option<Timer>);
+
+impl ShakeSuppressed {
+    /// Suppresses the shake until removed.
+    pub fn new() -> Self {
+        Self(None)
+    }
+
+    /// Suppresses the shake for `duration`, then removes itself.
+    pub fn from_duration(duration: Duration) -> Self {
+        Self(Some(Timer::new(duration, TimerMode::Once)))
+    }
 }
 
-fn shake(mut shakes: Query<(&mut Shake, &mut Transform, Option<&ShakeSettings>)>, time: Res<Time>) {
-    for (mut shake, mut transform, settings) in &mut shakes {
-        if shake.paused {
+fn tick_suppression(
+    mut commands: Commands,
+    mut suppressed: Query<(Entity, &mut ShakeSuppressed)>,
+    time: Res<Time>,
+) {
+    for (entity, mut suppressed) in suppressed.iter_mut() {
+        if let Some(timer) = &mut suppressed.0 {
+            if timer.tick(time.delta()).finished() {
+                commands.entity(entity).remove::<ShakeSuppressed>();
+            }
+        }
+    }
+}
+
+fn shake(
+    mut shakes: Query<(
+        &mut Shake,
+        &mut Transform,
+        Option<&ShakeSettings>,
+        Option<&Projection>,
+        Has<ShakeSuppressed>,
+        Option<&CameraBounds>,
+    )>,
+    canvas: Option<Res<CanvasDimensions>>,
+    visible: VisibleWorldRect,
+    reduced_motion: Option<Res<ReducedMotion>>,
+    time: Res<Time>,
+) {
+    let motion = motion_factor(reduced_motion.as_deref());
+    for (mut shake, mut transform, settings, projection, suppressed, bounds) in &mut shakes {
+        if shake.paused || suppressed {
             continue;
         }
 
@@ -136,18 +401,39 @@ fn shake(mut shakes: Query<(&mut Shake, &mut Transform, Option<&ShakeSettings>)>
 
         shake.reference_translation = Some(transform.translation);
 
-        let lacunarity = 2.;
-        let gain = 0.5;
-        let noise_pos = vec2(settings.frequency * time.elapsed_secs(), 0.);
-        let offset = settings.amplitude
+        let zoom = match projection {
+            Some(Projection::Orthographic(ortho)) if settings.scale_with_zoom => ortho.scale,
+            _ => 1.,
+        };
+
+        let pixel_scale = canvas
+            .as_ref()
+            .filter(|_| settings.canvas_pixels)
+            .map_or(1., |canvas| canvas.pixel_scale);
+
+        let offset = settings.amplitude / pixel_scale
+            * motion
+            * zoom
             * trauma_amount
-            * Vec2::new(
-                noise::fbm_simplex_2d(noise_pos + vec2(0., 1.), settings.octaves, lacunarity, gain),
-                noise::fbm_simplex_2d(noise_pos + vec2(0., 2.), settings.octaves, lacunarity, gain),
-            );
+            * settings
+                .noise
+                .sample(settings.frequency * time.elapsed_secs(), settings.octaves);
+
+        let offset = if settings.local_space {
+            transform.rotation * offset.extend(0.)
+        } else {
+            offset.extend(0.)
+        };
 
-        transform.translation.x += offset.x;
-        transform.translation.y += offset.y;
+        let mut shaken = transform.translation.xy() + offset.xy();
+        if let Some(region) = bounds.and_then(|bounds| visible.camera_region(bounds.rect)) {
+            // a camera that is already outside of the region is not snapped back in
+            let base = transform.translation.xy();
+            shaken = shaken.clamp(region.min.min(base), region.max.max(base));
+        }
+
+        transform.translation.x = shaken.x;
+        transform.translation.y = shaken.y;
     }
 }
 
@@ -161,33 +447,70 @@ fn restore(mut shakes: Query<(&mut Shake, &mut Transform)>) {
     }
 }
 
-/// Extension trait for [`Command`], adding commands for easily applying trauma
+/// Applies trauma to all [`Shake`]s.
+///
+/// Events sent before [`PostUpdate`] are applied in the same frame.
+#[derive(Debug, Clone, Copy, Event)]
+pub struct AddTrauma(pub f32);
+
+fn apply_trauma(
+    mut events: EventReader<AddTrauma>,
+    mut shakes: Query<&mut Shake>,
+    log: Option<ResMut<OptixCommandLog>>,
+    time: Res<Time>,
+) {
+    let events = events.read().map(|event| event.0).collect::<Vec<_>>();
+    // recorded here rather than in the commands, so that events sent directly are replayed too
+    if let Some(mut log) = log.filter(|log| log.recording()) {
+        for trauma in events.iter() {
+            log.record(time.elapsed(), OptixCommand::Trauma(*trauma));
+        }
+    }
+
+    let trauma = events.iter().sum::<f32>();
+    if trauma == 0. {
+        return;
+    }
+
+    for mut shake in shakes.iter_mut() {
+        shake.add_trauma(trauma);
+    }
+}
+
+/// Extension trait for [`Commands`], adding commands for easily applying trauma
 /// fire-and-forget-style.
 pub trait TraumaCommands {
     /// Applies the given trauma to all `Shake`s
     /// ```
     /// # use bevy::prelude::*;
-    /// use bevy_trauma_shake::prelude::*;
+    /// use bevy_optix::shake::prelude::*;
     ///
     /// fn add_shake(mut commands: Commands) {
     ///     commands.add_trauma(0.2);
     /// }
     /// ```
     fn add_trauma(&mut self, trauma: f32);
+
+    /// Suppresses all `Shake`s for `duration` with [`ShakeSuppressed`].
+    fn suppress_shake(&mut self, duration: Duration);
 }
 
 impl TraumaCommands for Commands<'_, '_> {
     fn add_trauma(&mut self, trauma: f32) {
-        self.queue(AddTraumaCommand(trauma));
+        self.send_event(AddTrauma(trauma));
     }
-}
 
-struct AddTraumaCommand(f32);
-
-impl Command for AddTraumaCommand {
-    fn apply(self, world: &mut World) {
-        for mut shake in world.query::<&mut Shake>().iter_mut(world) {
-            shake.add_trauma(self.0);
-        }
+    fn suppress_shake(&mut self, duration: Duration) {
+        self.queue(move |world: &mut World| {
+            let shakes = world
+                .query_filtered::<Entity, With<Shake>>()
+                .iter(world)
+                .collect::<Vec<_>>();
+            for entity in shakes {
+                world
+                    .entity_mut(entity)
+                    .insert(ShakeSuppressed::from_duration(duration));
+            }
+        });
     }
 }
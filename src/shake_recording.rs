@@ -0,0 +1,137 @@
+//! Records the offsets of a [`Shake`], for comparing [`ShakeSettings`] objectively and replaying
+//! a captured shake deterministically.
+//!
+//! ```
+//! # use bevy_optix::shake_recording::ShakeRecording;
+//! let recording = ShakeRecording::from_csv("time,x,y\n0,0,0\n0.1,2.5,-1\n0.2,0,0\n").unwrap();
+//! assert_eq!(recording.duration(), 0.2);
+//! assert_eq!(ShakeRecording::from_csv(&recording.to_csv()), Ok(recording));
+//! ```
+
+use crate::shake::{Shake, ShakeNoise, ShakeSettings};
+use bevy::math::curve::sample_curves::UnevenSampleAutoCurve;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Offsets of a [`Shake`], in world units, sampled at seconds since the recording started.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ShakeRecording {
+    samples: Vec<(f32, Vec2)>,
+}
+
+impl ShakeRecording {
+    pub fn samples(&self) -> &[(f32, Vec2)] {
+        &self.samples
+    }
+
+    /// Seconds between the first and last sample.
+    pub fn duration(&self) -> f32 {
+        match (self.samples.first(), self.samples.last()) {
+            (Some((start, _)), Some((end, _))) => end - start,
+            _ => 0.,
+        }
+    }
+
+    /// The offsets interpolated over time, or `None` with fewer than two samples.
+    pub fn curve(&self) -> Option<UnevenSampleAutoCurve<Vec2>> {
+        UnevenSampleAutoCurve::new(self.samples.iter().copied()).ok()
+    }
+
+    /// [`ShakeSettings`] that reproduce the recording verbatim at full trauma, looping over its
+    /// duration.
+    ///
+    /// The curve is sampled at the elapsed time, so a replay in a fresh [`App`] starts from the
+    /// beginning of the recording. Add a trauma of `1` to play it.
+    pub fn replay_settings(&self) -> Option<ShakeSettings> {
+        Some(ShakeSettings {
+            amplitude: 1.,
+            trauma_power: 1.,
+            decay_per_second: 0.,
+            frequency: 1.,
+            noise: ShakeNoise::curve(self.curve()?),
+            ..default()
+        })
+    }
+
+    /// Formats the samples as `time,x,y` rows with a header.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("time,x,y\n");
+        for (time, offset) in self.samples.iter() {
+            csv.push_str(&format!("{time},{},{}\n", offset.x, offset.y));
+        }
+        csv
+    }
+
+    /// Parses `time,x,y` rows, skipping a header and blank lines.
+    pub fn from_csv(csv: &str) -> Result<Self, ShakeCsvError> {
+        let samples = csv
+            .lines()
+            .enumerate()
+            .filter(|(_, row)| !row.trim().is_empty() && !row.starts_with("time"))
+            .map(|(line, row)| {
+                let columns = row
+                    .split(',')
+                    .map(|column| column.trim().parse::<f32>())
+                    .collect::<Result<Vec<_>, _>>();
+                match columns.as_deref() {
+                    Ok(&[time, x, y]) => Ok((time, Vec2::new(x, y))),
+                    _ => Err(ShakeCsvError { line: line + 1 }),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { samples })
+    }
+}
+
+/// A row of a [`ShakeRecording`] CSV that is not `time,x,y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShakeCsvError {
+    pub line: usize,
+}
+
+impl std::fmt::Display for ShakeCsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected `time,x,y` on line {}", self.line)
+    }
+}
+
+impl std::error::Error for ShakeCsvError {}
+
+/// Records the offset of the entity's [`Shake`] every frame into a [`ShakeRecording`].
+#[derive(Debug, Default, Clone, Component)]
+#[require(Shake)]
+pub struct ShakeRecorder {
+    recording: ShakeRecording,
+    start: Option<Duration>,
+}
+
+impl ShakeRecorder {
+    pub fn recording(&self) -> &ShakeRecording {
+        &self.recording
+    }
+
+    /// Takes the recording, starting a new one on the next frame.
+    pub fn take(&mut self) -> ShakeRecording {
+        self.start = None;
+        std::mem::take(&mut self.recording)
+    }
+}
+
+pub(crate) fn record_shake(
+    mut recorders: Query<(&mut ShakeRecorder, &Shake, &Transform)>,
+    time: Res<Time>,
+) {
+    for (mut recorder, shake, transform) in recorders.iter_mut() {
+        let start = *recorder.start.get_or_insert(time.elapsed());
+        let offset = shake
+            .reference_translation()
+            .map_or(Vec2::ZERO, |reference| {
+                (transform.translation - reference).xy()
+            });
+        recorder
+            .recording
+            .samples
+            .push(((time.elapsed() - start).as_secs_f32(), offset));
+    }
+}
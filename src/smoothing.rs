@@ -0,0 +1,80 @@
+//! Frame rate independent smoothing shared by the camera's follow, offset, and zoom behaviours.
+//!
+//! Every function takes the frame's delta time, so the same motion is produced at any frame rate:
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_optix::smoothing::smooth_damp;
+//! let simulate = |fps: f32| {
+//!     let (mut position, mut velocity) = (Vec2::ZERO, Vec2::ZERO);
+//!     for _ in 0..fps as usize {
+//!         position = smooth_damp(position, Vec2::splat(100.), &mut velocity, 0.25, 1. / fps);
+//!     }
+//!     position
+//! };
+//!
+//! let at_60 = simulate(60.);
+//! assert!(simulate(30.).distance(at_60) < 1e-3);
+//! assert!(simulate(240.).distance(at_60) < 1e-3);
+//! ```
+
+use bevy::math::VectorSpace;
+
+/// Exponentially decays `current` toward `target`, closing `1 - e^(-rate)` of the distance every
+/// second.
+///
+/// Replaces per-frame lerp factors such as `current.lerp(target, 0.1)`, which converge faster at
+/// higher frame rates.
+pub fn decay<V: VectorSpace>(current: V, target: V, rate: f32, dt: f32) -> V {
+    current.lerp(target, 1. - (-rate * dt).exp())
+}
+
+/// Moves `current` toward `target` with a critically damped spring that settles in roughly
+/// `smooth_time` seconds, storing the spring's state in `velocity`.
+///
+/// Solved exactly, so the result does not depend on how `dt` is divided.
+pub fn smooth_damp<V: VectorSpace>(
+    current: V,
+    target: V,
+    velocity: &mut V,
+    smooth_time: f32,
+    dt: f32,
+) -> V {
+    let frequency = 2. / smooth_time.max(f32::EPSILON);
+    let offset = current - target;
+    let decay = (-frequency * dt).exp();
+    let temp = (*velocity + offset * frequency) * dt;
+
+    *velocity = (*velocity - temp * frequency) * decay;
+    target + (offset + temp) * decay
+}
+
+/// Largest step taken by [`spring`], keeping stiff springs stable and the result consistent
+/// across frame rates.
+pub const SPRING_STEP: f32 = 1. / 240.;
+
+/// Moves `current` toward `target` with a damped spring, storing the spring's state in
+/// `velocity`.
+///
+/// A `damping` of `2 * stiffness.sqrt()` is critically damped. Integrated with implicit Euler in
+/// steps of at most [`SPRING_STEP`].
+pub fn spring<V: VectorSpace>(
+    mut current: V,
+    target: V,
+    velocity: &mut V,
+    stiffness: f32,
+    damping: f32,
+    dt: f32,
+) -> V {
+    let mut remaining = dt;
+    while remaining > 0. {
+        let dt = remaining.min(SPRING_STEP);
+        remaining -= dt;
+
+        *velocity = (*velocity - (current - target) * (dt * stiffness))
+            / (1. + dt * damping + dt * dt * stiffness);
+        current = current + *velocity * dt;
+    }
+
+    current
+}
@@ -0,0 +1,132 @@
+use crate::camera::{Binded, MainCamera, MoveTo, SpringFollow};
+use crate::ease::OptixEase;
+use crate::glitch::GlitchSettings;
+use crate::shake::{Shake, ShakeSettings};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// A copy of the [`MainCamera`] rig's state which can be restored later, e.g. when returning to
+/// the previous view after a cutscene.
+///
+/// Capture outside of [`PostUpdate`], where shake and bias offsets are not applied to the
+/// camera's translation.
+#[derive(Debug, Clone)]
+pub struct CameraSnapshot {
+    pub translation: Vec3,
+    /// The orthographic projection's scale.
+    pub zoom: Option<f32>,
+    pub binded: Option<Entity>,
+    pub spring_follow: Option<SpringFollow>,
+    pub shake: Option<Shake>,
+    pub shake_settings: Option<ShakeSettings>,
+    pub glitch: Option<GlitchSettings>,
+}
+
+/// Blends the [`MainCamera`] from its current translation to a [`CameraSnapshot`].
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotBlend {
+    pub duration: Duration,
+    pub easing: OptixEase,
+}
+
+impl SnapshotBlend {
+    pub fn new(duration: Duration, easing: impl Into<OptixEase>) -> Self {
+        Self {
+            duration,
+            easing: easing.into(),
+        }
+    }
+}
+
+impl CameraSnapshot {
+    /// Records the [`MainCamera`], returning `None` if there is not exactly one.
+    pub fn capture(world: &mut World) -> Option<Self> {
+        let camera = world
+            .query_filtered::<Entity, With<MainCamera>>()
+            .single(world)
+            .ok()?;
+        let camera = world.entity(camera);
+
+        Some(Self {
+            translation: camera.get::<Transform>()?.translation,
+            zoom: match camera.get::<Projection>() {
+                Some(Projection::Orthographic(ortho)) => Some(ortho.scale),
+                _ => None,
+            },
+            binded: camera.get::<Binded>().map(|binded| binded.0),
+            spring_follow: camera.get::<SpringFollow>().copied(),
+            shake: camera.get::<Shake>().cloned(),
+            shake_settings: camera.get::<ShakeSettings>().cloned(),
+            glitch: camera.get::<GlitchSettings>().copied(),
+        })
+    }
+
+    /// Restores the [`MainCamera`] to this snapshot.
+    ///
+    /// With a `blend`, the camera moves to the snapshot's translation, or its bound entity, with a
+    /// [`MoveTo`]. The remaining state is applied immediately.
+    ///
+    /// Captured components that are absent from the snapshot are removed from the camera. A
+    /// [`SpringFollow`] takes precedence over [`Binded`], so the camera is never left with both.
+    pub fn apply(&self, world: &mut World, blend: Option<SnapshotBlend>) -> Result {
+        let camera = world
+            .query_filtered::<Entity, With<MainCamera>>()
+            .single(world)?;
+        let mut camera = world.entity_mut(camera);
+
+        if let Some(Projection::Orthographic(ortho)) = camera.get_mut::<Projection>().as_deref_mut()
+        {
+            if let Some(zoom) = self.zoom {
+                ortho.scale = zoom;
+            }
+        }
+
+        camera.remove::<(
+            MoveTo,
+            Binded,
+            SpringFollow,
+            Shake,
+            ShakeSettings,
+            GlitchSettings,
+        )>();
+        let binded = self.binded.filter(|_| self.spring_follow.is_none());
+        match (blend, binded) {
+            (Some(blend), target) => {
+                let easing = blend.easing;
+                camera.insert(match target {
+                    Some(target) => MoveTo::from_current_to_entity(target, blend.duration, easing),
+                    None => {
+                        let start = camera
+                            .get::<Transform>()
+                            .map(|transform| transform.translation)
+                            .unwrap_or_default();
+                        MoveTo::new(blend.duration, start, self.translation, easing)
+                    }
+                });
+            }
+            (None, target) => {
+                if let Some(mut transform) = camera.get_mut::<Transform>() {
+                    transform.translation = self.translation;
+                }
+                if let Some(target) = target {
+                    camera.insert(Binded(target));
+                }
+            }
+        }
+
+        if let Some(spring_follow) = self.spring_follow {
+            camera.insert(spring_follow);
+        }
+        if let Some(shake) = self.shake.clone() {
+            camera.insert(shake);
+        }
+        if let Some(shake_settings) = self.shake_settings.clone() {
+            camera.insert(shake_settings);
+        }
+        if let Some(glitch) = self.glitch {
+            camera.insert(glitch);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+//! Helpers for testing camera behavior in a headless [`App`].
+//!
+//! Enabled with the `test-utils` feature.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_optix::assert_camera_at;
+//! # use bevy_optix::camera::Binded;
+//! # use bevy_optix::test_utils::{OptixTestExt, optix_test_app};
+//! let mut app = optix_test_app();
+//! let target = app.spawn_target(Vec2::new(10., 20.));
+//! let camera = app.spawn_camera();
+//! app.world_mut().entity_mut(camera).insert(Binded(target));
+//!
+//! app.step(1);
+//! assert_camera_at!(app, Vec2::new(10., 20.));
+//! ```
+
+use crate::camera::MainCamera;
+use crate::headless::{OptixTestAppExt, TEST_FRAME_TIME};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Builds an [`App`] with [`OptixTestAppExt::add_optix_test_plugins`].
+///
+/// The app is updated once, so that every following update advances [`Time`] by
+/// [`TEST_FRAME_TIME`].
+pub fn optix_test_app() -> App {
+    let mut app = App::new();
+    app.add_optix_test_plugins();
+    app.update();
+    app
+}
+
+pub trait OptixTestExt {
+    /// Spawns a [`MainCamera`] at the origin.
+    fn spawn_camera(&mut self) -> Entity;
+
+    /// Spawns an entity at `translation` for the camera to follow.
+    fn spawn_target(&mut self, translation: Vec2) -> Entity;
+
+    /// Updates the app `frames` times.
+    fn step(&mut self, frames: usize);
+
+    /// Updates the app until at least `duration` has elapsed, in steps of [`TEST_FRAME_TIME`].
+    fn step_for(&mut self, duration: Duration);
+
+    /// The translation of the single [`MainCamera`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one [`MainCamera`].
+    fn camera_translation(&mut self) -> Vec2;
+}
+
+impl OptixTestExt for App {
+    fn spawn_camera(&mut self) -> Entity {
+        self.world_mut().spawn((MainCamera, Camera2d)).id()
+    }
+
+    fn spawn_target(&mut self, translation: Vec2) -> Entity {
+        self.world_mut()
+            .spawn(Transform::from_translation(translation.extend(0.)))
+            .id()
+    }
+
+    fn step(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.update();
+        }
+    }
+
+    fn step_for(&mut self, duration: Duration) {
+        let frames = duration.as_nanos().div_ceil(TEST_FRAME_TIME.as_nanos());
+        self.step(frames as usize);
+    }
+
+    fn camera_translation(&mut self) -> Vec2 {
+        let world = self.world_mut();
+        world
+            .query_filtered::<&Transform, With<MainCamera>>()
+            .single(world)
+            .expect("expected exactly one `MainCamera`")
+            .translation
+            .xy()
+    }
+}
+
+/// Asserts that the [`MainCamera`] of an [`App`] is within `epsilon` of a [`Vec2`], `1e-3` by
+/// default.
+///
+/// [`MainCamera`]: crate::camera::MainCamera
+/// [`App`]: bevy::app::App
+/// [`Vec2`]: bevy::math::Vec2
+#[macro_export]
+macro_rules! assert_camera_at {
+    ($app:expr, $expected:expr $(,)?) => {
+        $crate::assert_camera_at!($app, $expected, 1e-3)
+    };
+    ($app:expr, $expected:expr, $epsilon:expr $(,)?) => {{
+        let actual = $crate::test_utils::OptixTestExt::camera_translation(&mut $app);
+        let expected: ::bevy::math::Vec2 = $expected;
+        assert!(
+            actual.abs_diff_eq(expected, $epsilon),
+            "expected the camera at {expected}, found {actual}",
+        );
+    }};
+}
@@ -0,0 +1,251 @@
+//! Keyframed camera cutscenes which do not depend on `bevy_sequence`.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_optix::timeline::*;
+//! fn intro(mut commands: Commands, mut timelines: ResMut<Assets<CameraTimeline>>) {
+//!     let timeline = timelines.add(
+//!         CameraTimeline::default()
+//!             .with(CameraKeyframe::new(0.).translation(Vec2::ZERO).zoom(1.))
+//!             .with(
+//!                 CameraKeyframe::new(2.)
+//!                     .translation(Vec2::new(200., 0.))
+//!                     .zoom(0.5)
+//!                     .easing(EaseFunction::CubicInOut),
+//!             )
+//!             .with(CameraKeyframe::new(2.5).trauma(0.6)),
+//!     );
+//!     commands.play_camera_timeline(timeline);
+//! }
+//! ```
+
+use crate::camera::{Binded, MainCamera};
+use crate::ease::OptixEase;
+use crate::shake::AddTrauma;
+use bevy::ecs::component::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+
+pub struct CameraTimelinePlugin;
+
+impl Plugin for CameraTimelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<CameraTimeline>();
+    }
+}
+
+/// A list of [`CameraKeyframe`]s played on the [`MainCamera`] with
+/// [`CameraTimelineCommands::play_camera_timeline`].
+///
+/// Translation and zoom are interpolated between the keyframes that set them, using the easing
+/// of the later keyframe.
+#[derive(Debug, Default, Clone, Asset, TypePath)]
+pub struct CameraTimeline {
+    keyframes: Vec<CameraKeyframe>,
+}
+
+impl CameraTimeline {
+    pub fn new(keyframes: impl IntoIterator<Item = CameraKeyframe>) -> Self {
+        keyframes
+            .into_iter()
+            .fold(Self::default(), |timeline, keyframe| {
+                timeline.with(keyframe)
+            })
+    }
+
+    pub fn with(mut self, keyframe: CameraKeyframe) -> Self {
+        let index = self
+            .keyframes
+            .partition_point(|other| other.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+        self
+    }
+
+    pub fn keyframes(&self) -> &[CameraKeyframe] {
+        &self.keyframes
+    }
+
+    /// The time of the last keyframe, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0., |keyframe| keyframe.time)
+    }
+
+    fn sample<T: Copy>(
+        &self,
+        time: f32,
+        channel: impl Fn(&CameraKeyframe) -> Option<T>,
+        lerp: impl Fn(T, T, f32) -> T,
+    ) -> Option<T> {
+        let mut previous = None;
+        for keyframe in self.keyframes.iter() {
+            let Some(value) = channel(keyframe) else {
+                continue;
+            };
+
+            if keyframe.time > time {
+                return Some(match previous {
+                    Some((start, value_start)) => {
+                        let t = (time - start) / (keyframe.time - start).max(f32::EPSILON);
+                        lerp(value_start, value, keyframe.easing.sample(t))
+                    }
+                    None => value,
+                });
+            }
+            previous = Some((keyframe.time, value));
+        }
+
+        previous.map(|(_, value)| value)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CameraKeyframe {
+    /// Seconds from the start of the timeline.
+    pub time: f32,
+    pub translation: Option<Vec2>,
+    /// The orthographic projection's scale.
+    pub zoom: Option<f32>,
+    /// The easing used to reach this keyframe.
+    pub easing: OptixEase,
+    /// Trauma applied to all shakes when this keyframe is reached.
+    pub trauma: Option<f32>,
+}
+
+impl CameraKeyframe {
+    pub fn new(time: f32) -> Self {
+        Self {
+            time,
+            translation: None,
+            zoom: None,
+            easing: OptixEase::default(),
+            trauma: None,
+        }
+    }
+
+    pub fn translation(mut self, translation: Vec2) -> Self {
+        self.translation = Some(translation);
+        self
+    }
+
+    pub fn zoom(mut self, zoom: f32) -> Self {
+        self.zoom = Some(zoom);
+        self
+    }
+
+    pub fn easing(mut self, easing: impl Into<OptixEase>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+
+    pub fn trauma(mut self, trauma: f32) -> Self {
+        self.trauma = Some(trauma);
+        self
+    }
+}
+
+/// A [`CameraTimeline`] playing on the [`MainCamera`].
+///
+/// Unbinds the camera, and removes itself once the timeline completes.
+#[derive(Debug, Clone, Component)]
+#[component(on_insert = on_insert_timeline)]
+pub struct PlayingTimeline {
+    timeline: Handle<CameraTimeline>,
+    elapsed: f32,
+    next_keyframe: usize,
+}
+
+impl PlayingTimeline {
+    pub fn new(timeline: Handle<CameraTimeline>) -> Self {
+        Self {
+            timeline,
+            elapsed: 0.,
+            next_keyframe: 0,
+        }
+    }
+
+    /// Seconds since the timeline started.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+}
+
+fn on_insert_timeline(mut world: DeferredWorld, context: HookContext) {
+    world.commands().entity(context.entity).remove::<Binded>();
+}
+
+pub trait CameraTimelineCommands {
+    /// Plays the timeline on the [`MainCamera`].
+    fn play_camera_timeline(&mut self, timeline: Handle<CameraTimeline>);
+}
+
+impl CameraTimelineCommands for Commands<'_, '_> {
+    fn play_camera_timeline(&mut self, timeline: Handle<CameraTimeline>) {
+        self.queue(move |world: &mut World| -> Result {
+            let camera = world
+                .query_filtered::<Entity, With<MainCamera>>()
+                .single(world)?;
+            world
+                .entity_mut(camera)
+                .insert(PlayingTimeline::new(timeline));
+            Ok(())
+        });
+    }
+}
+
+pub(crate) fn play_timeline(
+    mut commands: Commands,
+    camera: Option<
+        Single<
+            (
+                Entity,
+                &mut Transform,
+                Option<&mut Projection>,
+                &mut PlayingTimeline,
+            ),
+            With<MainCamera>,
+        >,
+    >,
+    timelines: Res<Assets<CameraTimeline>>,
+    mut trauma: EventWriter<AddTrauma>,
+    time: Res<Time>,
+) {
+    let Some((entity, mut transform, projection, mut playing)) = camera.map(|c| c.into_inner())
+    else {
+        return;
+    };
+
+    // Wait for the timeline to load.
+    let Some(timeline) = timelines.get(&playing.timeline) else {
+        return;
+    };
+
+    playing.elapsed += time.delta_secs();
+    let elapsed = playing.elapsed;
+
+    while let Some(keyframe) = timeline
+        .keyframes
+        .get(playing.next_keyframe)
+        .filter(|keyframe| keyframe.time <= elapsed)
+    {
+        if let Some(amount) = keyframe.trauma {
+            trauma.write(AddTrauma(amount));
+        }
+        playing.next_keyframe += 1;
+    }
+
+    if let Some(translation) = timeline.sample(elapsed, |k| k.translation, Vec2::lerp) {
+        transform.translation = translation.extend(transform.translation.z);
+    }
+
+    if let Some(zoom) = timeline.sample(elapsed, |k| k.zoom, FloatExt::lerp) {
+        if let Some(mut projection) = projection {
+            if let Projection::Orthographic(ortho) = projection.as_mut() {
+                ortho.scale = zoom;
+            }
+        }
+    }
+
+    if elapsed >= timeline.duration() {
+        commands.entity(entity).remove::<PlayingTimeline>();
+    }
+}
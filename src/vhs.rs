@@ -0,0 +1,60 @@
+use crate::post_process::prelude::{PostProcessMaterial, PostProcessPlugin};
+use bevy::asset::weak_handle;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_resource::ShaderRef;
+use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::ShaderType};
+use serde::Deserialize;
+
+pub const VHS_SHADER_HANDLE: Handle<Shader> = weak_handle!("1c8f4a62-b7e3-4d95-a02f-6e9b3d7c1f48");
+
+pub struct VhsPlugin;
+
+impl Plugin for VhsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<VhsSettings>()
+            .add_plugins(PostProcessPlugin::<VhsSettings>::default());
+
+        if !app.world().contains_resource::<Assets<Shader>>() {
+            return;
+        }
+
+        load_internal_asset!(
+            app,
+            VHS_SHADER_HANDLE,
+            "shaders/vhs.wgsl",
+            Shader::from_wgsl
+        );
+    }
+}
+
+/// Worn tape playback, stackable with or in place of the
+/// [`GlitchSettings`](crate::glitch::GlitchSettings).
+#[derive(Debug, Component, Clone, Copy, ExtractComponent, ShaderType, Reflect, Deserialize)]
+#[serde(default)]
+pub struct VhsSettings {
+    /// Strength of the rolling bands that tear the picture sideways.
+    pub tracking: f32,
+    /// Strength of the slow horizontal drift.
+    pub wobble: f32,
+    /// How far the color smears to the right of the picture.
+    pub chroma_bleed: f32,
+    /// Height of the torn bar at the bottom of the screen, in UVs.
+    pub head_switch: f32,
+}
+
+impl Default for VhsSettings {
+    fn default() -> Self {
+        Self {
+            tracking: 0.3,
+            wobble: 0.5,
+            chroma_bleed: 1.,
+            head_switch: 0.03,
+        }
+    }
+}
+
+impl PostProcessMaterial for VhsSettings {
+    fn fragment_shader() -> ShaderRef {
+        VHS_SHADER_HANDLE.into()
+    }
+}
@@ -0,0 +1,155 @@
+use crate::camera::{MainCamera, MoveTo};
+use crate::ease::OptixEase;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// A shot that the [`MainCamera`] can take, directed by a [`CameraDirector`].
+///
+/// The director follows the highest priority virtual camera, blending into it over its `blend`
+/// duration whenever the active camera changes.
+#[derive(Component)]
+#[require(Transform)]
+pub struct VirtualCamera {
+    pub priority: i32,
+    pub source: VirtualCameraSource,
+    /// The orthographic scale of the shot, or the [`MainCamera`]'s current scale if `None`.
+    pub zoom: Option<f32>,
+    /// The duration of the blend into this camera.
+    pub blend: Duration,
+    pub easing: OptixEase,
+}
+
+impl VirtualCamera {
+    pub fn new(priority: i32) -> Self {
+        Self {
+            priority,
+            source: VirtualCameraSource::Transform,
+            zoom: None,
+            blend: Duration::from_secs(1),
+            easing: EaseFunction::SmoothStep.into(),
+        }
+    }
+
+    /// Follows `entity` instead of the virtual camera's own translation.
+    pub fn following(mut self, entity: Entity) -> Self {
+        self.source = VirtualCameraSource::Follow(entity);
+        self
+    }
+
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = Some(zoom);
+        self
+    }
+
+    pub fn with_blend(mut self, blend: Duration, easing: impl Into<OptixEase>) -> Self {
+        self.blend = blend;
+        self.easing = easing.into();
+        self
+    }
+}
+
+/// Where a [`VirtualCamera`] is positioned.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum VirtualCameraSource {
+    /// The virtual camera's own translation.
+    #[default]
+    Transform,
+    /// The translation of another entity, falling back to the virtual camera's own translation
+    /// if the entity does not exist.
+    Follow(Entity),
+}
+
+/// Positions the [`MainCamera`] with the highest priority [`VirtualCamera`].
+///
+/// Suspended while the camera has a [`MoveTo`], so scripted moves take precedence.
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct CameraDirector {
+    active: Option<Entity>,
+    blend: Option<Blend>,
+}
+
+impl CameraDirector {
+    /// The [`VirtualCamera`] currently directing the [`MainCamera`].
+    pub fn active(&self) -> Option<Entity> {
+        self.active
+    }
+
+    /// Whether the [`MainCamera`] is blending into the active [`VirtualCamera`].
+    pub fn blending(&self) -> bool {
+        self.blend.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Blend {
+    translation: Vec2,
+    zoom: f32,
+    elapsed: Duration,
+}
+
+pub(crate) fn direct_virtual_cameras(
+    camera: Option<
+        Single<
+            (&mut Transform, &mut Projection, &mut CameraDirector),
+            (With<MainCamera>, Without<MoveTo>),
+        >,
+    >,
+    cameras: Query<(Entity, &VirtualCamera, &GlobalTransform)>,
+    targets: Query<&GlobalTransform>,
+    time: Res<Time>,
+) {
+    let Some((mut transform, mut projection, mut director)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = projection.as_mut() else {
+        return;
+    };
+
+    let Some((entity, shot, shot_transform)) = cameras
+        .iter()
+        .max_by_key(|(entity, shot, _)| (shot.priority, *entity))
+    else {
+        director.active = None;
+        director.blend = None;
+        return;
+    };
+
+    if director.active != Some(entity) {
+        // the first shot is cut to, later shots are blended into
+        director.blend = director.active.map(|_| Blend {
+            translation: transform.translation.xy(),
+            zoom: ortho.scale,
+            elapsed: Duration::ZERO,
+        });
+        director.active = Some(entity);
+    }
+
+    let translation = match shot.source {
+        VirtualCameraSource::Transform => None,
+        VirtualCameraSource::Follow(target) => targets.get(target).ok(),
+    }
+    .unwrap_or(shot_transform)
+    .translation()
+    .xy();
+    let zoom = shot.zoom.unwrap_or(ortho.scale);
+
+    let (translation, zoom) = match director.blend.as_mut() {
+        Some(blend) if blend.elapsed < shot.blend => {
+            blend.elapsed += time.delta();
+            let t = shot
+                .easing
+                .sample(blend.elapsed.as_secs_f32() / shot.blend.as_secs_f32());
+            (
+                blend.translation.lerp(translation, t),
+                blend.zoom.lerp(zoom, t),
+            )
+        }
+        _ => {
+            director.blend = None;
+            (translation, zoom)
+        }
+    };
+
+    transform.translation = translation.extend(transform.translation.z);
+    ortho.scale = zoom;
+}
@@ -0,0 +1,127 @@
+use crate::camera::MainCamera;
+use crate::pixel_perfect::{CanvasDimensions, OuterCamera};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// The region of the world that is actually visible through the [`MainCamera`].
+///
+/// When the canvas is cropped by the [`OuterCamera`], the visible region is smaller than the
+/// [`MainCamera`]'s projection. Every system that clamps the camera should use this so that they
+/// agree on what is on screen.
+#[derive(SystemParam)]
+pub struct VisibleWorldRect<'w, 's> {
+    camera: Query<'w, 's, (&'static Projection, &'static GlobalTransform), With<MainCamera>>,
+    outer: Query<'w, 's, &'static Projection, (With<OuterCamera>, Without<MainCamera>)>,
+    dimensions: Option<Res<'w, CanvasDimensions>>,
+}
+
+impl VisibleWorldRect<'_, '_> {
+    /// The size of the visible region in world units.
+    pub fn size(&self) -> Option<Vec2> {
+        let (Projection::Orthographic(projection), _) = self.camera.single().ok()? else {
+            return None;
+        };
+        let mut size = projection.area.size();
+
+        if let (Some(dimensions), Ok(Projection::Orthographic(outer))) =
+            (self.dimensions.as_deref(), self.outer.single())
+        {
+            let canvas = Vec2::new(dimensions.width as f32, dimensions.height as f32)
+                * dimensions.pixel_scale;
+            let outer = outer.area.size();
+            if outer.cmpgt(Vec2::ZERO).all() && canvas.cmpgt(Vec2::ZERO).all() {
+                size *= (outer / canvas).min(Vec2::ONE);
+            }
+        }
+
+        Some(size)
+    }
+
+    /// The visible region if the camera were centered on `center`.
+    pub fn rect_at(&self, center: Vec2) -> Option<Rect> {
+        self.size().map(|size| Rect::from_center_size(center, size))
+    }
+
+    /// The orthographic scale at which the visible region exactly fits `rect`.
+    pub fn scale_to_fit(&self, rect: Rect) -> Option<f32> {
+        let (Projection::Orthographic(projection), _) = self.camera.single().ok()? else {
+            return None;
+        };
+        let unscaled = self.size()? / projection.scale;
+        if unscaled.cmple(Vec2::ZERO).any() {
+            return None;
+        }
+
+        Some((rect.size() / unscaled).max_element())
+    }
+
+    /// The visible region as of the last transform propagation.
+    pub fn rect(&self) -> Option<Rect> {
+        let (_, transform) = self.camera.single().ok()?;
+        self.rect_at(transform.translation().xy())
+    }
+
+    /// The region in which the camera's center can move without seeing outside of `bounds`.
+    pub fn camera_region(&self, bounds: Rect) -> Option<Rect> {
+        self.size().map(|size| camera_region(bounds, size))
+    }
+}
+
+/// Hides the entity while it is more than `margin` world units outside of the
+/// [`VisibleWorldRect`], reducing extraction costs for debug primitives and props in large worlds.
+///
+/// Takes ownership of the entity's [`Visibility`], which is [`Visibility::Inherited`] while near
+/// the camera and [`Visibility::Hidden`] otherwise.
+#[derive(Debug, Default, Clone, Copy, Component)]
+#[require(Visibility)]
+pub struct VisibleOnlyNearCamera {
+    pub margin: f32,
+}
+
+impl VisibleOnlyNearCamera {
+    pub fn new(margin: f32) -> Self {
+        Self { margin }
+    }
+}
+
+pub(crate) fn cull_far_from_camera(
+    visible: VisibleWorldRect,
+    mut entities: Query<(&GlobalTransform, &VisibleOnlyNearCamera, &mut Visibility)>,
+) {
+    let Some(rect) = visible.rect() else {
+        return;
+    };
+
+    for (transform, near, mut visibility) in entities.iter_mut() {
+        let near_camera = rect
+            .inflate(near.margin)
+            .contains(transform.translation().xy());
+        visibility.set_if_neq(if near_camera {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
+
+/// Returns the region in which a camera viewing `view` world units can move without seeing
+/// outside of `bounds`.
+///
+/// If `bounds` is smaller than the view along an axis, the camera is centered on that axis.
+pub fn camera_region(bounds: Rect, view: Vec2) -> Rect {
+    let half = view / 2.;
+    let center = bounds.center();
+    let min = bounds.min + half;
+    let max = bounds.max - half;
+
+    Rect {
+        min: Vec2::new(
+            if min.x <= max.x { min.x } else { center.x },
+            if min.y <= max.y { min.y } else { center.y },
+        ),
+        max: Vec2::new(
+            if min.x <= max.x { max.x } else { center.x },
+            if min.y <= max.y { max.y } else { center.y },
+        ),
+    }
+}
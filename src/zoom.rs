@@ -0,0 +1,255 @@
+use crate::camera::{MainCamera, MoveTo};
+use crate::ease::OptixEase;
+use crate::replay::{OptixCommand, record_command};
+use crate::smoothing::smooth_damp;
+use crate::visible::VisibleWorldRect;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Animates the orthographic scale of the [`MainCamera`] from `start` to `end`.
+#[derive(Component)]
+pub struct ZoomTo {
+    timer: Timer,
+    start: f32,
+    end: f32,
+    easing: OptixEase,
+}
+
+impl ZoomTo {
+    pub fn new(duration: Duration, start: f32, end: f32, easing: impl Into<OptixEase>) -> Self {
+        Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            start,
+            end,
+            easing: easing.into(),
+        }
+    }
+
+    pub fn end(&self) -> f32 {
+        self.end
+    }
+
+    /// The eased scale at the current time.
+    pub fn scale(&self) -> f32 {
+        self.start
+            .lerp(self.end, self.easing.sample(self.timer.fraction()))
+    }
+}
+
+/// Momentarily zooms the [`MainCamera`] in, then springs back.
+///
+/// Applied on top of the projection's scale, and removed in [`PreUpdate`], so it composes with
+/// [`ZoomTo`] and anything else controlling the zoom.
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct ZoomPunch {
+    /// The fraction of the scale currently removed by the punch.
+    amount: f32,
+    velocity: f32,
+    smooth_time: f32,
+    applied: Option<f32>,
+}
+
+impl ZoomPunch {
+    /// Zooms in by `strength`, a fraction of the current scale, returning in roughly `duration`.
+    pub fn punch(&mut self, strength: f32, duration: Duration) {
+        self.amount = (self.amount + strength).clamp(0., 0.95);
+        self.smooth_time = duration.as_secs_f32() / 3.;
+    }
+
+    pub fn amount(&self) -> f32 {
+        self.amount
+    }
+}
+
+pub trait ZoomCommands {
+    /// Animates the [`MainCamera`]'s orthographic scale to `scale`.
+    fn zoom_to(&mut self, scale: f32, duration: Duration, easing: impl Into<OptixEase>);
+
+    /// Moves the [`MainCamera`] to `translation` while animating its orthographic scale to `scale`,
+    /// driven by a single [`MoveTo`] timer.
+    fn move_and_zoom_to(
+        &mut self,
+        translation: Vec2,
+        scale: f32,
+        duration: Duration,
+        easing: impl Into<OptixEase>,
+    );
+
+    /// Moves and zooms the [`MainCamera`] so that its visible region exactly frames `rect`, grown
+    /// by `padding` on every side.
+    fn frame_rect(
+        &mut self,
+        rect: Rect,
+        padding: f32,
+        duration: Duration,
+        easing: impl Into<OptixEase>,
+    );
+
+    /// Zooms the [`MainCamera`] in by `strength`, a fraction of the current scale, springing back
+    /// over `duration`.
+    fn zoom_punch(&mut self, strength: f32, duration: Duration);
+}
+
+impl ZoomCommands for Commands<'_, '_> {
+    fn zoom_to(&mut self, scale: f32, duration: Duration, easing: impl Into<OptixEase>) {
+        let easing = easing.into();
+        self.queue(move |world: &mut World| -> Result {
+            record_command(
+                world,
+                OptixCommand::ZoomTo {
+                    scale,
+                    duration,
+                    easing,
+                },
+            );
+            let (camera, projection) = world
+                .query_filtered::<(Entity, &Projection), With<MainCamera>>()
+                .single(world)?;
+            let start = match projection {
+                Projection::Orthographic(ortho) => ortho.scale,
+                _ => 1.,
+            };
+            world
+                .entity_mut(camera)
+                .insert(ZoomTo::new(duration, start, scale, easing));
+            Ok(())
+        });
+    }
+
+    fn move_and_zoom_to(
+        &mut self,
+        translation: Vec2,
+        scale: f32,
+        duration: Duration,
+        easing: impl Into<OptixEase>,
+    ) {
+        let easing = easing.into();
+        self.queue(move |world: &mut World| -> Result {
+            record_command(
+                world,
+                OptixCommand::MoveAndZoomTo {
+                    translation,
+                    scale,
+                    duration,
+                    easing,
+                },
+            );
+            let (camera, projection) = world
+                .query_filtered::<(Entity, &Projection), With<MainCamera>>()
+                .single(world)?;
+            let start_scale = match projection {
+                Projection::Orthographic(ortho) => ortho.scale,
+                _ => 1.,
+            };
+            world.entity_mut(camera).remove::<ZoomTo>().insert(
+                MoveTo::from_current(translation, duration, easing).with_zoom(start_scale, scale),
+            );
+            Ok(())
+        });
+    }
+
+    fn frame_rect(
+        &mut self,
+        rect: Rect,
+        padding: f32,
+        duration: Duration,
+        easing: impl Into<OptixEase>,
+    ) {
+        let easing = easing.into();
+        self.queue(move |world: &mut World| -> Result {
+            let rect = rect.inflate(padding);
+            let mut visible = SystemState::<VisibleWorldRect>::new(world);
+            let Some(scale) = visible.get(world).scale_to_fit(rect) else {
+                return Err("could not measure the main camera's visible region".into());
+            };
+
+            world
+                .commands()
+                .move_and_zoom_to(rect.center(), scale, duration, easing);
+            Ok(())
+        });
+    }
+
+    fn zoom_punch(&mut self, strength: f32, duration: Duration) {
+        self.queue(move |world: &mut World| -> Result {
+            record_command(world, OptixCommand::ZoomPunch { strength, duration });
+            let camera = world
+                .query_filtered::<Entity, With<MainCamera>>()
+                .single(world)?;
+            world
+                .entity_mut(camera)
+                .entry::<ZoomPunch>()
+                .or_default()
+                .into_mut()
+                .punch(strength, duration);
+            Ok(())
+        });
+    }
+}
+
+pub(crate) fn zoom_to(
+    mut commands: Commands,
+    camera: Option<Single<(Entity, &mut Projection, &mut ZoomTo), With<MainCamera>>>,
+    time: Res<Time>,
+) {
+    let Some((entity, mut projection, mut zoom)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    zoom.timer.tick(time.delta());
+    if let Projection::Orthographic(ortho) = projection.as_mut() {
+        ortho.scale = zoom.scale();
+    }
+
+    if zoom.timer.finished() {
+        commands.entity(entity).remove::<ZoomTo>();
+    }
+}
+
+pub(crate) fn zoom_punch(
+    mut commands: Commands,
+    camera: Option<Single<(Entity, &mut Projection, &mut ZoomPunch), With<MainCamera>>>,
+    time: Res<Time>,
+) {
+    let Some((entity, mut projection, mut punch)) = camera.map(|c| c.into_inner()) else {
+        return;
+    };
+
+    let Projection::Orthographic(ortho) = projection.as_mut() else {
+        return;
+    };
+
+    let smooth_time = punch.smooth_time;
+    let mut velocity = punch.velocity;
+    punch.amount = smooth_damp(
+        punch.amount,
+        0.,
+        &mut velocity,
+        smooth_time,
+        time.delta_secs(),
+    );
+    punch.velocity = velocity;
+
+    if punch.amount.abs() < 1e-4 && punch.velocity.abs() < 1e-3 {
+        commands.entity(entity).remove::<ZoomPunch>();
+        return;
+    }
+
+    let factor = 1. - punch.amount;
+    ortho.scale *= factor;
+    punch.applied = Some(factor);
+}
+
+pub(crate) fn restore_zoom_punch(mut punches: Query<(&mut Projection, &mut ZoomPunch)>) {
+    for (mut projection, mut punch) in punches.iter_mut() {
+        let Some(factor) = punch.applied else {
+            continue;
+        };
+
+        if let Projection::Orthographic(ortho) = projection.as_mut() {
+            ortho.scale /= factor;
+        }
+        punch.applied = None;
+    }
+}
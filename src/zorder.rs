@@ -1,58 +1,215 @@
+use crate::camera::MainCamera;
 use bevy::prelude::*;
 
 pub struct ZOrderPlugin;
 
 impl Plugin for ZOrderPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<ZOrderSettings>().add_systems(
             PostUpdate,
-            (order_z, origin_y).before(TransformSystem::TransformPropagate),
+            (order_z, (sprite_origin_y, origin_y).chain())
+                .before(TransformSystem::TransformPropagate),
         );
     }
 }
 
+/// Configures how a [`YOrigin`] maps the entity's y position into a [`ZOrder`].
+///
+/// The order is `base - y / divisor`, clamped to `[min, max]`.
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct ZOrderSettings {
+    /// The y distance that moves an entity by one unit of z.
+    ///
+    /// Should exceed the range of y coordinates in the game, otherwise the order may push
+    /// entities beyond the camera's far plane or across explicit z layers.
+    pub divisor: f32,
+    /// Added to every order.
+    pub base: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Default for ZOrderSettings {
+    fn default() -> Self {
+        Self {
+            divisor: 10_000.,
+            base: 0.,
+            min: f32::NEG_INFINITY,
+            max: f32::INFINITY,
+        }
+    }
+}
+
+impl ZOrderSettings {
+    /// The order of an entity at `y`.
+    pub fn order(&self, y: f32) -> f32 {
+        (self.base - y / self.divisor).clamp(self.min, self.max)
+    }
+}
+
 /// Determines the y offset from the entity's [`Transform`] by which the [`ZOrder`] is calculated.
-#[derive(Debug, Default, Clone, Copy, Component)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[require(ZOrder, OrderedY)]
 pub struct YOrigin(pub f32);
 
+/// The y by which the entity's [`ZOrder`] was last calculated.
+#[derive(Debug, Default, Clone, Copy, Component)]
+struct OrderedY(Option<f32>);
+
+/// Derives the [`YOrigin`] from the feet of the entity's [`Sprite`], updated as the sprite's
+/// anchor, size, or frame change.
+///
+/// The sprite's size is taken from its `custom_size`, otherwise its `rect`, texture atlas frame,
+/// or image. The entity's scale is not taken into account.
+#[derive(Debug, Default, Clone, Copy, Component)]
+#[require(YOrigin)]
+pub struct SpriteYOrigin;
+
+/// The size at which `sprite` is drawn, if its image or atlas is loaded.
+fn sprite_size(
+    sprite: &Sprite,
+    images: &Assets<Image>,
+    layouts: &Assets<TextureAtlasLayout>,
+) -> Option<Vec2> {
+    sprite
+        .custom_size
+        .or_else(|| sprite.rect.map(|rect| rect.size()))
+        .or_else(|| {
+            sprite
+                .texture_atlas
+                .as_ref()
+                .and_then(|atlas| atlas.texture_rect(layouts))
+                .map(|rect| rect.size().as_vec2())
+        })
+        .or_else(|| images.get(&sprite.image).map(|image| image.size_f32()))
+}
+
+fn sprite_origin_y(
+    mut sprites: Query<(&Sprite, &mut YOrigin), With<SpriteYOrigin>>,
+    images: Option<Res<Assets<Image>>>,
+    layouts: Option<Res<Assets<TextureAtlasLayout>>>,
+) {
+    let (Some(images), Some(layouts)) = (images, layouts) else {
+        return;
+    };
+
+    // Images and atlases may load after the sprite changes, so every sprite is checked.
+    for (sprite, mut origin) in sprites.iter_mut() {
+        let Some(size) = sprite_size(sprite, &images, &layouts) else {
+            continue;
+        };
+
+        let feet = -(sprite.anchor.as_vec().y + 0.5) * size.y;
+        origin.set_if_neq(YOrigin(feet));
+    }
+}
+
+/// The y offset of the pivot in the entity's current animation frame, added to its [`YOrigin`].
+///
+/// Animation integrations, such as Aseprite or texture atlas players, should write the pivot of
+/// each frame so that characters whose pivots move between frames keep a stable sort order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+pub struct FramePivot(pub f32);
+
+type OriginData = (
+    &'static GlobalTransform,
+    &'static YOrigin,
+    Option<&'static FramePivot>,
+    &'static mut OrderedY,
+    &'static mut ZOrder,
+);
+
 fn origin_y(
-    mut commands: Commands,
-    origin_query: Query<
-        (Entity, &GlobalTransform, &YOrigin),
-        Or<(Changed<Transform>, Changed<YOrigin>)>,
-    >,
+    mut origins: ParamSet<(
+        Query<OriginData>,
+        Query<
+            OriginData,
+            Or<(
+                Changed<GlobalTransform>,
+                Changed<YOrigin>,
+                Changed<FramePivot>,
+            )>,
+        >,
+    )>,
+    mut removed_pivots: RemovedComponents<FramePivot>,
+    settings: Res<ZOrderSettings>,
 ) {
-    for (entity, transform, origin) in origin_query.iter() {
-        let order = -(origin.0 + transform.translation().y) / 10_000.;
-        commands.entity(entity).insert(ZOrder(order));
+    if settings.is_changed() {
+        for (global, origin, pivot, mut ordered, mut z) in origins.p0().iter_mut() {
+            let y = sorted_y(global, origin, pivot);
+            ordered.0 = Some(y);
+            z.set_if_neq(ZOrder(settings.order(y)));
+        }
+        return;
+    }
+
+    {
+        let mut all = origins.p0();
+        let mut removed = all.iter_many_mut(removed_pivots.read());
+        while let Some((global, origin, pivot, ordered, z)) = removed.fetch_next() {
+            update_order(sorted_y(global, origin, pivot), ordered, z, &settings);
+        }
+    }
+
+    // the global transform lags a frame behind the transform, so it is what triggers a resort
+    for (global, origin, pivot, ordered, z) in origins.p1().iter_mut() {
+        update_order(sorted_y(global, origin, pivot), ordered, z, &settings);
     }
 }
 
+fn sorted_y(global: &GlobalTransform, origin: &YOrigin, pivot: Option<&FramePivot>) -> f32 {
+    origin.0 + pivot.map(|pivot| pivot.0).unwrap_or_default() + global.translation().y
+}
+
+fn update_order(y: f32, mut ordered: Mut<OrderedY>, mut z: Mut<ZOrder>, settings: &ZOrderSettings) {
+    // ordering moves the entity along z only, which should not trigger another pass
+    if ordered.0 == Some(y) {
+        return;
+    }
+
+    ordered.0 = Some(y);
+    z.set_if_neq(ZOrder(settings.order(y)));
+}
+
 /// Describes the order that entities are drawn.
 ///
 /// Use the [`YOrigin`] to generate a [`ZOrder`] automatically from the entities position.
-#[derive(Debug, Default, Clone, Copy, Component)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[require(UnorderedZ)]
 pub struct ZOrder(pub f32);
 
+/// The entity's z translation before the [`ZOrder`] was applied.
 #[derive(Debug, Default, Clone, Copy, Component)]
-struct UnorderedZ(pub f32);
+struct UnorderedZ(Option<f32>);
 
 fn order_z(
-    mut commands: Commands,
-    mut changed_order_query: Query<(&ZOrder, &UnorderedZ, &mut Transform), Changed<ZOrder>>,
-    mut new_order_query: Query<
-        (Entity, &ZOrder, &mut Transform),
-        (Changed<ZOrder>, Without<UnorderedZ>),
-    >,
+    mut order_query: Query<(Entity, &ZOrder, &mut UnorderedZ, &mut Transform), Changed<ZOrder>>,
+    camera: Option<Single<(&Transform, &Projection), (With<MainCamera>, Without<ZOrder>)>>,
 ) {
-    for (entity, order, mut transform) in new_order_query.iter_mut() {
-        commands
-            .entity(entity)
-            .insert(UnorderedZ(transform.translation.z));
-        transform.translation.z += order.0;
-    }
+    // The range of z visible to the camera.
+    let visible = camera.and_then(|camera| {
+        let (transform, projection) = camera.into_inner();
+        match projection {
+            Projection::Orthographic(ortho) => Some((
+                transform.translation.z - ortho.far,
+                transform.translation.z - ortho.near,
+            )),
+            _ => None,
+        }
+    });
+
+    for (entity, order, mut unordered, mut transform) in order_query.iter_mut() {
+        let z = *unordered.0.get_or_insert(transform.translation.z);
+        transform.translation.z = z + order.0;
 
-    for (order, unordered, mut transform) in changed_order_query.iter_mut() {
-        transform.translation.z = unordered.0 + order.0;
+        if let Some((min, max)) = visible {
+            if transform.translation.z < min || transform.translation.z > max {
+                warn_once!(
+                    "ZOrder places {entity} at z = {} outside of the main camera's clipping \
+                    planes, consider configuring the ZOrderSettings",
+                    transform.translation.z
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+use bevy_optix::anchor::{AnchorHandoff, AnchorTarget, CameraAnchor};
+use bevy_optix::assert_camera_at;
+use bevy_optix::camera::{AimFraming, Binded, MoveTo};
+use bevy_optix::framing::{FrameGroup, FrameWeight};
+use bevy_optix::rooms::{CameraRoom, CurrentRoom, RoomTransition};
+use bevy_optix::test_utils::{OptixTestExt, optix_test_app};
+use bevy_optix::virtual_camera::{CameraDirector, VirtualCamera};
+use std::time::Duration;
+
+#[test]
+fn binded_camera_follows_target() {
+    let mut app = optix_test_app();
+    let target = app.spawn_target(Vec2::new(10., 20.));
+    let camera = app.spawn_camera();
+    app.world_mut().entity_mut(camera).insert(Binded(target));
+
+    app.step(1);
+    assert_camera_at!(app, Vec2::new(10., 20.));
+
+    app.world_mut()
+        .get_mut::<Transform>(target)
+        .unwrap()
+        .translation = Vec3::new(-5., 3., 0.);
+    app.step(1);
+    assert_camera_at!(app, Vec2::new(-5., 3.));
+}
+
+#[test]
+fn aim_framing_weighs_and_clamps_aim_point() {
+    let mut app = optix_test_app();
+    let target = app.spawn_target(Vec2::ZERO);
+    let aim = app.spawn_target(Vec2::new(40., 0.));
+    let camera = app.spawn_camera();
+    app.world_mut().entity_mut(camera).insert((
+        Binded(target),
+        AimFraming::new(aim).with_target_weight(0.75),
+    ));
+
+    app.step(1);
+    assert_camera_at!(app, Vec2::new(10., 0.));
+
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(AimFraming::new(aim).with_max_distance(5.));
+    app.step(1);
+    assert_camera_at!(app, Vec2::new(5., 0.));
+}
+
+#[test]
+fn move_to_eases_and_lands_on_end() {
+    let mut app = optix_test_app();
+    let camera = app.spawn_camera();
+    app.world_mut().entity_mut(camera).insert(MoveTo::new(
+        Duration::from_secs(1),
+        Vec3::ZERO,
+        Vec3::new(100., 0., 0.),
+        EaseFunction::Linear,
+    ));
+
+    app.step_for(Duration::from_millis(500));
+    assert_camera_at!(app, Vec2::new(50., 0.), 0.1);
+
+    app.step_for(Duration::from_millis(500));
+    assert_camera_at!(app, Vec2::new(100., 0.));
+    assert!(app.world().get::<MoveTo>(camera).is_none());
+}
+
+#[test]
+fn move_to_moves_props_without_binding() {
+    let mut app = optix_test_app();
+    let prop = app.spawn_target(Vec2::ZERO);
+    let target = app.spawn_target(Vec2::new(0., 30.));
+    let camera = app.spawn_camera();
+    app.world_mut()
+        .entity_mut(prop)
+        .insert(MoveTo::new_with_entity(
+            Duration::from_millis(100),
+            Vec3::ZERO,
+            target,
+            EaseFunction::Linear,
+        ));
+
+    app.step_for(Duration::from_millis(100));
+    app.step(1);
+    assert_eq!(
+        app.world().get::<Transform>(prop).unwrap().translation.xy(),
+        Vec2::new(0., 30.)
+    );
+    assert!(app.world().get::<Binded>(prop).is_none());
+    assert_camera_at!(app, Vec2::ZERO);
+    assert!(app.world().get::<Binded>(camera).is_none());
+}
+
+#[test]
+fn move_to_entity_binds_on_completion() {
+    let mut app = optix_test_app();
+    let target = app.spawn_target(Vec2::new(40., 0.));
+    let camera = app.spawn_camera();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(MoveTo::new_with_entity(
+            Duration::from_millis(100),
+            Vec3::ZERO,
+            target,
+            EaseFunction::Linear,
+        ));
+
+    app.step_for(Duration::from_millis(100));
+    app.step(1);
+    assert_camera_at!(app, Vec2::new(40., 0.));
+    assert_eq!(
+        app.world().get::<Binded>(camera).map(|binded| binded.0),
+        Some(target)
+    );
+}
+
+#[test]
+fn anchor_overrides_binded_axes() {
+    let mut app = optix_test_app();
+    let target = app.spawn_target(Vec2::new(10., 20.));
+    let camera = app.spawn_camera();
+    app.world_mut().entity_mut(camera).insert(Binded(target));
+    app.world_mut().spawn((
+        CameraAnchor::with_axes(Vec2::Y),
+        Transform::from_xyz(50., 50., 0.),
+    ));
+
+    app.step(1);
+    assert_camera_at!(app, Vec2::new(10., 50.));
+}
+
+#[test]
+fn camera_without_bindings_stays_put() {
+    let mut app = optix_test_app();
+    app.spawn_camera();
+    app.step(10);
+    assert_camera_at!(app, Vec2::ZERO);
+}
+
+#[test]
+fn virtual_camera_overrides_anchor_handoff() {
+    let mut app = optix_test_app();
+    let target = app.spawn_target(Vec2::new(100., 0.));
+    let camera = app.spawn_camera();
+    app.world_mut().entity_mut(camera).insert((
+        CameraDirector::default(),
+        AnchorHandoff::new(target, Duration::from_secs(1)),
+    ));
+    app.world_mut().spawn((
+        VirtualCamera::new(0).with_blend(Duration::ZERO, EaseFunction::Linear),
+        Transform::from_xyz(-50., 20., 0.),
+    ));
+
+    // the shot's global transform is propagated after the first frame
+    app.step(2);
+    for _ in 0..30 {
+        app.step(1);
+        assert_camera_at!(app, Vec2::new(-50., 20.));
+    }
+    assert!(app.world().get::<Binded>(camera).is_none());
+}
+
+#[test]
+fn despawned_frame_target_fades_out() {
+    let mut app = optix_test_app();
+    let anchor = app.spawn_target(Vec2::ZERO);
+    let enemy = app.spawn_target(Vec2::new(100., 0.));
+    app.world_mut()
+        .entity_mut(anchor)
+        .insert(FrameWeight::with_ramp(1., Duration::ZERO));
+    app.world_mut()
+        .entity_mut(enemy)
+        .insert(FrameWeight::with_ramp(1., Duration::from_millis(500)));
+    let camera = app.spawn_camera();
+    app.world_mut().entity_mut(camera).insert(FrameGroup);
+
+    app.step_for(Duration::from_secs(1));
+    assert_camera_at!(app, Vec2::new(50., 0.));
+
+    app.world_mut().despawn(enemy);
+    app.step(1);
+    let x = app.camera_translation().x;
+    assert!(x > 40. && x <= 50., "the camera jumped to {x}");
+
+    app.step_for(Duration::from_millis(250));
+    let halfway = app.camera_translation().x;
+    assert!(
+        halfway > 0. && halfway < x,
+        "the camera stopped at {halfway}"
+    );
+
+    app.step_for(Duration::from_millis(300));
+    assert_camera_at!(app, Vec2::ZERO);
+}
+
+#[test]
+fn rooms_follow_the_binded_target() {
+    let mut app = optix_test_app();
+    let left = app
+        .world_mut()
+        .spawn(CameraRoom::new(Rect::new(-100., -100., 0., 100.)))
+        .id();
+    let right = app
+        .world_mut()
+        .spawn(CameraRoom::new(Rect::new(0., -100., 100., 100.)))
+        .id();
+    let other = app.spawn_target(Vec2::new(50., 0.));
+    let player = app.spawn_target(Vec2::new(-50., 0.));
+    app.world_mut().entity_mut(other).insert(AnchorTarget);
+    app.world_mut().entity_mut(player).insert(AnchorTarget);
+    let camera = app.spawn_camera();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert((RoomTransition::default(), Binded(player)));
+
+    app.step(1);
+    assert_eq!(
+        app.world().get::<CurrentRoom>(camera),
+        Some(&CurrentRoom(left))
+    );
+
+    app.world_mut()
+        .get_mut::<Transform>(player)
+        .unwrap()
+        .translation = Vec3::new(50., 0., 0.);
+    app.step_for(Duration::from_secs(1));
+    assert_eq!(
+        app.world().get::<CurrentRoom>(camera),
+        Some(&CurrentRoom(right))
+    );
+    assert_eq!(
+        app.world().get::<Binded>(camera).map(|binded| binded.0),
+        Some(player)
+    );
+}
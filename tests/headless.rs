@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy_optix::assert_camera_at;
+use bevy_optix::camera::{Binded, MainCamera};
+use bevy_optix::headless::OptixTestAppExt;
+use bevy_optix::pixel_perfect::{
+    CanvasBorder, CanvasDimensions, MainCanvas, OuterCamera, PixelPerfectPlugin,
+};
+use bevy_optix::test_utils::{OptixTestExt, optix_test_app};
+
+fn camera_translations(app: &mut App) -> Vec<Vec2> {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Transform, With<MainCamera>>()
+        .iter(world)
+        .map(|transform| transform.translation.xy())
+        .collect()
+}
+
+#[test]
+fn despawned_camera_is_skipped() {
+    let mut app = optix_test_app();
+    let target = app.spawn_target(Vec2::new(10., 0.));
+    let camera = app.spawn_camera();
+    app.world_mut().entity_mut(camera).insert(Binded(target));
+    app.step(1);
+
+    app.world_mut().despawn(camera);
+    app.step(3);
+    assert!(camera_translations(&mut app).is_empty());
+
+    let camera = app.spawn_camera();
+    app.world_mut().entity_mut(camera).insert(Binded(target));
+    app.step(1);
+    assert_camera_at!(app, Vec2::new(10., 0.));
+}
+
+#[test]
+fn duplicate_cameras_are_skipped() {
+    let mut app = optix_test_app();
+    let target = app.spawn_target(Vec2::new(10., 0.));
+    for _ in 0..2 {
+        let camera = app.spawn_camera();
+        app.world_mut().entity_mut(camera).insert(Binded(target));
+    }
+
+    app.step(3);
+    assert_eq!(camera_translations(&mut app), vec![Vec2::ZERO; 2]);
+}
+
+#[test]
+fn canvas_border_skips_missing_canvas_and_outer_camera() {
+    let mut app = App::new();
+    app.add_optix_test_plugins()
+        .add_plugins(PixelPerfectPlugin(CanvasDimensions::new(320, 180, 1.)));
+    app.update();
+
+    let border = app.world_mut().spawn(CanvasBorder::fill()).id();
+    let canvas = app
+        .world_mut()
+        .query_filtered::<Entity, With<MainCanvas>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .get_mut::<Transform>(canvas)
+        .unwrap()
+        .translation = Vec3::new(5., 5., -999.9);
+    app.step(1);
+
+    let border_translation = |app: &App| {
+        app.world()
+            .get::<Transform>(border)
+            .unwrap()
+            .translation
+            .xy()
+    };
+    assert_eq!(border_translation(&app), Vec2::new(5., 5.));
+
+    app.world_mut().despawn(canvas);
+    app.step(1);
+    assert_eq!(border_translation(&app), Vec2::new(5., 5.));
+
+    let outer = app
+        .world_mut()
+        .query_filtered::<Entity, With<OuterCamera>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().despawn(outer);
+    app.world_mut().spawn((MainCanvas, Transform::default()));
+    app.step(1);
+    assert_eq!(border_translation(&app), Vec2::new(5., 5.));
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+use bevy_optix::headless::OptixTestAppExt;
+use bevy_optix::pixel_perfect::{
+    CanvasDimensions, HIGH_RES_LAYER, LayerAssignment, PixelPerfectPlugin,
+};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_optix_test_plugins()
+        .add_plugins(PixelPerfectPlugin(CanvasDimensions::new(320, 180, 1.)));
+    app.update();
+    app
+}
+
+fn layers(app: &App, entity: Entity) -> Option<RenderLayers> {
+    app.world().get::<RenderLayers>(entity).cloned()
+}
+
+#[test]
+fn unassigned_parent_does_not_change_children() {
+    let mut app = app();
+    let child = app.world_mut().spawn(Transform::default()).id();
+    let explicit = app
+        .world_mut()
+        .spawn((Transform::default(), RenderLayers::layer(5)))
+        .id();
+    app.world_mut()
+        .spawn((Transform::default(), RenderLayers::layer(3)))
+        .add_children(&[child, explicit]);
+
+    app.update();
+    assert_eq!(layers(&app, child), None);
+    assert_eq!(layers(&app, explicit), Some(RenderLayers::layer(5)));
+}
+
+#[test]
+fn assignment_propagates_to_descendants() {
+    let mut app = app();
+    let explicit = app
+        .world_mut()
+        .spawn((Transform::default(), RenderLayers::layer(5)))
+        .id();
+    let child = app.world_mut().spawn(Transform::default()).id();
+    let root = app
+        .world_mut()
+        .spawn((Transform::default(), LayerAssignment::HighRes))
+        .add_children(&[child, explicit])
+        .id();
+
+    app.update();
+    assert_eq!(layers(&app, root), Some(HIGH_RES_LAYER));
+    assert_eq!(layers(&app, child), Some(HIGH_RES_LAYER));
+    assert_eq!(layers(&app, explicit), Some(RenderLayers::layer(5)));
+
+    // spawned later, below the assigned root
+    let grandchild = app.world_mut().spawn(ChildOf(child)).id();
+    app.update();
+    assert_eq!(layers(&app, grandchild), Some(HIGH_RES_LAYER));
+
+    app.world_mut()
+        .entity_mut(root)
+        .insert(LayerAssignment::Canvas);
+    app.update();
+    assert_eq!(layers(&app, grandchild), Some(RenderLayers::default()));
+    assert_eq!(layers(&app, explicit), Some(RenderLayers::layer(5)));
+}
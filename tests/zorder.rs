@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use bevy_optix::test_utils::{OptixTestExt, optix_test_app};
+use bevy_optix::zorder::{YOrigin, ZOrder, ZOrderSettings};
+
+fn z(app: &App, entity: Entity) -> f32 {
+    app.world().get::<Transform>(entity).unwrap().translation.z
+}
+
+#[test]
+fn lower_entities_draw_in_front() {
+    let mut app = optix_test_app();
+    let above = app
+        .world_mut()
+        .spawn((Transform::from_xyz(0., 100., 0.), YOrigin(0.)))
+        .id();
+    let below = app
+        .world_mut()
+        .spawn((Transform::from_xyz(0., -100., 0.), YOrigin(0.)))
+        .id();
+
+    app.step(3);
+    let settings = *app.world().resource::<ZOrderSettings>();
+    assert_eq!(z(&app, above), settings.order(100.));
+    assert_eq!(z(&app, below), settings.order(-100.));
+    assert!(z(&app, below) > z(&app, above));
+}
+
+#[test]
+fn order_follows_movement() {
+    let mut app = optix_test_app();
+    let entity = app
+        .world_mut()
+        .spawn((Transform::from_xyz(0., 100., 0.), YOrigin(-10.)))
+        .id();
+    app.step(3);
+
+    app.world_mut()
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation
+        .y = -100.;
+    app.step(3);
+
+    let settings = *app.world().resource::<ZOrderSettings>();
+    assert_eq!(
+        app.world().get::<ZOrder>(entity).copied(),
+        Some(ZOrder(settings.order(-110.)))
+    );
+}
+
+#[test]
+fn explicit_z_is_preserved() {
+    let mut app = optix_test_app();
+    let entity = app
+        .world_mut()
+        .spawn((Transform::from_xyz(0., 0., 5.), ZOrder(1.)))
+        .id();
+
+    app.step(1);
+    assert_eq!(z(&app, entity), 6.);
+}